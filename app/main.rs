@@ -1,12 +1,10 @@
 use std::env;
 use std::process;
 
-use hyper::StatusCode;
-
-use icfp::ast::{demodulate_string, modulate_to_string, Symbol};
+use icfp::ast::Symbol;
 use icfp::client::Client as AlienClient;
-use icfp::stack_interpreter::{Effects, Resolve, VM};
-use image::{GrayImage, ImageFormat};
+use icfp::stack_interpreter::{normalize, Effects, Resolve, VM};
+use image::{ImageFormat, RgbImage};
 use std::ops::Deref;
 use std::time::SystemTime;
 
@@ -17,7 +15,7 @@ impl Effects for CliEffects {
         unimplemented!()
     }
 
-    fn display(&self, image: &GrayImage) {
+    fn display(&self, image: &RgbImage) {
         let name = SystemTime::now()
             .duration_since(SystemTime::UNIX_EPOCH)
             .unwrap();
@@ -38,13 +36,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     println!("ServerUrl: {}; PlayerKey: {}", server_url, player_key);
     let client = AlienClient::new(server_url, player_key);
 
-    let mut program = Symbol::List(vec![Symbol::Lit(0)]);
+    let mut program = Symbol::List(vec![Symbol::Lit(0.into())]);
 
     let vm = VM::new_effects(Box::new(CliEffects {}));
 
     for _i in 0..50 {
         dbg!(&program);
-        let response = dbg!(send_program(&client, &program).await);
+        let normalized = normalize(&vm, &program.clone().into());
+        let response = match client.send_and_confirm(normalized.deref()).await {
+            Ok(symbol) => dbg!(symbol),
+            Err(err) => {
+                println!("Giving up on alien proxy: {:?}", err);
+                process::exit(1)
+            }
+        };
         program = vm
             .run_symbols(&[Symbol::Ap, Symbol::Inc, Symbol::Ap, Symbol::Car, response])
             .deref()
@@ -53,38 +58,3 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 
     Ok(())
 }
-
-async fn send_program(client: &AlienClient, program: &Symbol) -> Symbol {
-    let program_string = modulate_to_string(&program);
-
-    match client.send(program_string).await {
-        Ok(res) => match res.status() {
-            StatusCode::OK => {
-                print!("Server response: ");
-                let text = res.text().await;
-                match text {
-                    Ok(content) => demodulate_string(content.as_str()),
-                    Err(why) => panic!("error reading body: {:?}", why),
-                }
-            }
-            _ => {
-                println!("Unexpected server response:");
-                println!("HTTP code: {}", res.status());
-                print!("Response body: ");
-
-                let text = res.text().await;
-
-                match text {
-                    Ok(content) => println!("{:?}", content),
-                    Err(why) => println!("error reading body: {:?}", why),
-                }
-
-                process::exit(2)
-            }
-        },
-        Err(err) => {
-            println!("Unexpected server response:\n{}", err);
-            process::exit(1)
-        }
-    }
-}
@@ -1,27 +1,46 @@
 // https://message-from-space.readthedocs.io/en/latest/message7.html
 
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter, Result};
 use std::ops::Deref;
 use std::rc::Rc;
+use std::thread_local;
 
 use image::GrayImage;
+use num_bigint::BigInt;
 
-pub use modulations::{demodulate_string, modulate_to_string};
+pub use modulations::{demodulate_string, modulate_to_string, DemodulateError, ModulateError};
 
-pub type Number = i64;
+/// Alien messages carry numbers far outside the range of any fixed-width
+/// integer, and the modulate encoding is itself variable-width, so `Lit`
+/// is backed by an arbitrary-precision integer rather than `i64`.
+pub type Number = BigInt;
 
-#[derive(Clone, Eq, PartialEq)]
+#[derive(Clone)]
 pub struct SymbolCell(Rc<Symbol>);
 
 impl From<Symbol> for SymbolCell {
     fn from(symbol: Symbol) -> Self {
-        SymbolCell(symbol.into())
+        intern(symbol)
     }
 }
 
 impl From<&Symbol> for SymbolCell {
     fn from(symbol: &Symbol) -> Self {
-        SymbolCell(symbol.clone().into())
+        intern(symbol.clone())
+    }
+}
+
+impl SymbolCell {
+    /// A stable identity for this cell's underlying `Rc` allocation, shared
+    /// by every clone. Used to memoize forcing a thunk so that a value
+    /// referenced from several places in the tree (e.g. a combinator's
+    /// captured argument) is only ever reduced once. Because `SymbolCell`s
+    /// are hash-consed, this is also shared by every other `SymbolCell`
+    /// holding a structurally-equal value.
+    pub(crate) fn thunk_key(&self) -> usize {
+        Rc::as_ptr(&self.0) as usize
     }
 }
 
@@ -33,6 +52,96 @@ impl Deref for SymbolCell {
     }
 }
 
+/// Hash-consing makes two structurally-equal symbols share one `Rc`
+/// allocation (see `intern`), so comparing the pointers is equivalent to -
+/// and much cheaper than - comparing the trees, provided every `SymbolCell`
+/// is built through `intern`.
+impl PartialEq for SymbolCell {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SymbolCell {}
+
+/// A structural key for interning a single `Symbol` node. Children that are
+/// themselves `SymbolCell`s contribute their `thunk_key` (the address of
+/// their shared `Rc`) rather than being walked again, which is only sound
+/// because `intern` guarantees children are always interned before their
+/// parents.
+#[derive(Clone, Eq, PartialEq, Hash)]
+enum InternKey {
+    /// The ~30 variants that carry no data (the combinators and `Nil`),
+    /// keyed by their discriminant.
+    Leaf(std::mem::Discriminant<Symbol>),
+    Lit(Number),
+    Var(Identifier),
+    StoreArg(Identifier),
+    Modulated(modulations::Modulated),
+    Pair(usize, usize),
+    Closure { captured_arg: usize, body: usize },
+    /// `List`/`Image` aren't interned (the former is lowered away before
+    /// evaluation, the latter carries pixel data nothing else will ever
+    /// match); each gets its own arena slot via a fresh counter value.
+    Uninterned(u64),
+}
+
+#[derive(Default)]
+struct Arena {
+    symbols: Vec<Rc<Symbol>>,
+    index: HashMap<InternKey, usize>,
+    next_uninterned: u64,
+}
+
+impl Arena {
+    fn intern(&mut self, symbol: Symbol, key: InternKey) -> SymbolCell {
+        if let InternKey::Uninterned(_) = key {
+            let rc = Rc::new(symbol);
+            self.symbols.push(rc.clone());
+            return SymbolCell(rc);
+        }
+
+        if let Some(&index) = self.index.get(&key) {
+            return SymbolCell(self.symbols[index].clone());
+        }
+
+        let index = self.symbols.len();
+        let rc = Rc::new(symbol);
+        self.symbols.push(rc.clone());
+        self.index.insert(key, index);
+        SymbolCell(rc)
+    }
+}
+
+thread_local! {
+    static ARENA: RefCell<Arena> = RefCell::new(Arena::default());
+}
+
+fn intern(symbol: Symbol) -> SymbolCell {
+    let key = match &symbol {
+        Symbol::Lit(n) => InternKey::Lit(n.clone()),
+        Symbol::Var(id) => InternKey::Var(id.clone()),
+        Symbol::StoreArg(id) => InternKey::StoreArg(id.clone()),
+        Symbol::Modulated(bits) => InternKey::Modulated(bits.clone()),
+        Symbol::Pair(fst, snd) => InternKey::Pair(fst.thunk_key(), snd.thunk_key()),
+        Symbol::Closure { captured_arg, body } => InternKey::Closure {
+            captured_arg: captured_arg.thunk_key(),
+            body: body.thunk_key(),
+        },
+        Symbol::List(_) | Symbol::Image(_) => {
+            InternKey::Uninterned(ARENA.with(|arena| {
+                let mut arena = arena.borrow_mut();
+                let next = arena.next_uninterned;
+                arena.next_uninterned += 1;
+                next
+            }))
+        }
+        leaf => InternKey::Leaf(std::mem::discriminant(leaf)),
+    };
+
+    ARENA.with(|arena| arena.borrow_mut().intern(symbol, key))
+}
+
 impl Debug for SymbolCell {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "{:?}", self.0)
@@ -1,7 +1,36 @@
+use num_bigint::{BigUint, Sign};
+
 use super::{Number, Symbol};
 
 pub type Modulated = Vec<bool>;
 
+/// Wire-format conversions between a bit vector and the byte buffer it's
+/// transmitted as, mirroring the classic `BitVec::to_bytes`/`from_bytes`
+/// layout: bit `i` lives at bit `7 - (i % 8)` of byte `i / 8`, MSB-first,
+/// zero-padded in the final byte.
+pub trait ModulatedBits {
+    fn to_bytes(&self) -> Vec<u8>;
+    fn from_bytes(bytes: &[u8], bit_len: usize) -> Modulated;
+}
+
+impl ModulatedBits for Modulated {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![0u8; (self.len() + 7) / 8];
+        for (i, &bit) in self.iter().enumerate() {
+            if bit {
+                bytes[i / 8] |= 1 << (7 - (i % 8));
+            }
+        }
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8], bit_len: usize) -> Modulated {
+        (0..bit_len)
+            .map(|i| bytes[i / 8] & (1 << (7 - (i % 8))) != 0)
+            .collect()
+    }
+}
+
 mod modulate_constants {
     pub(super) const MODULATED_LIST: [bool; 2] = [true, true];
     pub(super) const NIL: [bool; 2] = [false, false];
@@ -10,6 +39,10 @@ mod modulate_constants {
     pub(super) const SIGN_NEGATIVE: [bool; 2] = [true, false];
 }
 
+/// Encodes `value`'s sign and magnitude as modulated bits. The unary width
+/// prefix has no fixed bound, so this is the only place in the crate that
+/// needs to encode number magnitude, and it does so via `Number`'s
+/// arbitrary-precision backing rather than a fixed-width integer.
 ///
 /// Bits 0..1 define a positive or negative number (and signal width) via a high/low or low/high signal change:
 //  01: positive number
@@ -32,31 +65,22 @@ mod modulate_constants {
 //  00010000: 16 <- 8 (4*2)
 //  000100000000: 256 <- 12 (4*3)
 //
-fn modulate_number(value: Number) -> Modulated {
-    if value == 0 {
+fn modulate_number(value: &Number) -> Modulated {
+    if value.sign() == Sign::NoSign {
         return modulate_constants::ZERO.to_vec();
     }
 
-    fn log_2(x: Number) -> u32 {
-        const fn num_bits<T>() -> usize {
-            std::mem::size_of::<T>() * 8
-        }
-
-        assert!(x > 0);
-        num_bits::<Number>() as u32 - x.leading_zeros() - 1
-    }
-
     let mut bits: Vec<bool> = Vec::new();
 
-    if value > 0 {
+    if value.sign() == Sign::Plus {
         bits.extend_from_slice(&modulate_constants::SIGN_POSITIVE);
     } else {
         bits.extend_from_slice(&modulate_constants::SIGN_NEGATIVE);
     }
 
-    let value = value.abs();
+    let magnitude = value.magnitude();
 
-    let number_of_bits_for_number = log_2(value) + 1;
+    let number_of_bits_for_number = magnitude.bits() as u32;
 
     let remainder = if number_of_bits_for_number % 4 != 0 {
         1
@@ -71,7 +95,7 @@ fn modulate_number(value: Number) -> Modulated {
     bits.push(false);
 
     if width > 0 {
-        let encoded = format!("{:0>width$b}", value, width = width);
+        let encoded = format!("{:0>width$}", magnitude.to_str_radix(2), width = width);
         let encoded: Vec<bool> = encoded.bytes().map(|b| b == b'1').collect();
         bits.extend_from_slice(&encoded);
     }
@@ -79,90 +103,194 @@ fn modulate_number(value: Number) -> Modulated {
     return bits;
 }
 
-pub fn modulate(value: &Symbol) -> Modulated {
+/// Why [`try_modulate`] failed to encode a `Symbol`, naming the irreducible
+/// sub-term it got stuck on - anything that isn't a number, `nil`, a pair or
+/// a list, such as an unsaturated combinator left over from a partial
+/// application.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModulateError {
+    pub symbol: String,
+}
+
+impl std::fmt::Display for ModulateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "cannot modulate non-data term: {}", self.symbol)
+    }
+}
+
+impl std::error::Error for ModulateError {}
+
+/// Fallible counterpart to [`modulate`]: a `Symbol` the interpreter hasn't
+/// fully reduced to data - most commonly a `Closure` left behind by a
+/// partially-applied combinator - reports a [`ModulateError`] naming that
+/// sub-term instead of panicking. Callers that may be handed such a value
+/// (e.g. straight out of the VM) should normalize it to weak-head-normal
+/// list form first; see `stack_interpreter::normalize`.
+pub fn try_modulate(value: &Symbol) -> Result<Modulated, ModulateError> {
     match value {
-        Symbol::Lit(number) => modulate_number(*number),
-        Symbol::Nil => modulate_constants::NIL.to_vec(),
+        Symbol::Lit(number) => Ok(modulate_number(number)),
+        Symbol::Nil => Ok(modulate_constants::NIL.to_vec()),
         Symbol::List(symbols) => {
-            let mut vec = symbols.iter().fold(
-                modulate_constants::MODULATED_LIST.to_vec(),
-                |mut vec, symbol| {
-                    vec.append(&mut modulate(symbol));
-                    vec
-                },
-            );
+            let mut vec = modulate_constants::MODULATED_LIST.to_vec();
+            for symbol in symbols {
+                vec.append(&mut try_modulate(symbol)?);
+            }
             vec.extend_from_slice(&modulate_constants::NIL);
-            vec
+            Ok(vec)
         }
         Symbol::Pair(left, right) => {
             let mut vec = modulate_constants::MODULATED_LIST.to_vec();
-            vec.extend_from_slice(&modulate(&left));
-            vec.extend_from_slice(&modulate(&right));
-            vec
+            vec.extend_from_slice(&try_modulate(left)?);
+            vec.extend_from_slice(&try_modulate(right)?);
+            Ok(vec)
         }
-        _ => unimplemented!("Not implemented for {:?} yet", value),
+        _ => Err(ModulateError {
+            symbol: format!("{:?}", value),
+        }),
     }
 }
 
-pub fn demodulate(value: Modulated) -> Symbol {
-    fn demodulate_number(sign: Number, slice: &[bool]) -> (usize, Symbol) {
+pub fn modulate(value: &Symbol) -> Modulated {
+    try_modulate(value).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Why [`try_demodulate`] failed to parse a modulated bit stream, with the
+/// bit offset into the original input where parsing gave up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DemodulateError {
+    /// Ran out of bits before a complete value could be read; `needed` is
+    /// how many more bits this step required.
+    UnexpectedEof { offset: usize, needed: usize },
+    /// The two prefix bits read at `offset` don't match any known tag.
+    InvalidPrefix { offset: usize, bits: (bool, bool) },
+    /// The top-level value parsed cleanly, but bits remain after it.
+    TrailingBits { offset: usize },
+}
+
+impl std::fmt::Display for DemodulateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DemodulateError::UnexpectedEof { offset, needed } => write!(
+                f,
+                "unexpected end of modulated input at bit {}, needed {} more bit(s)",
+                offset, needed
+            ),
+            DemodulateError::InvalidPrefix { offset, bits } => {
+                write!(f, "invalid prefix {:?} at bit {}", bits, offset)
+            }
+            DemodulateError::TrailingBits { offset } => {
+                write!(f, "trailing bits starting at bit {}", offset)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DemodulateError {}
+
+/// Fallible counterpart to [`demodulate`]: a truncated or corrupt
+/// transmission returns a [`DemodulateError`] naming the bit offset where
+/// parsing gave up, rather than panicking.
+pub fn try_demodulate(value: &Modulated) -> Result<Symbol, DemodulateError> {
+    fn demodulate_number(
+        negative: bool,
+        slice: &[bool],
+        offset: usize,
+    ) -> Result<(usize, Symbol), DemodulateError> {
         let width = slice.iter().take_while(|&&b| b).count();
 
+        if width == slice.len() {
+            return Err(DemodulateError::UnexpectedEof {
+                offset: offset + width,
+                needed: 1,
+            });
+        }
+
         if width == 0 {
-            return (1, Symbol::Lit(0));
+            return Ok((1, Symbol::Lit(Number::from(0))));
         }
 
         let width_bits = width + 1;
         let bit_size = width_bits + (width * 4);
 
-        let parsed_value = slice[width_bits..bit_size]
+        if slice.len() < bit_size {
+            return Err(DemodulateError::UnexpectedEof {
+                offset: offset + slice.len(),
+                needed: bit_size - slice.len(),
+            });
+        }
+
+        let binary: String = slice[width_bits..bit_size]
             .iter()
-            .fold(0i64, |num, bit| num << 1 | if *bit { 1 } else { 0 });
+            .map(|&bit| if bit { '1' } else { '0' })
+            .collect();
 
-        let parsed_value = sign * parsed_value;
+        let magnitude = BigUint::from_str_radix(&binary, 2)
+            .expect("a string of '0'/'1' characters is always valid base 2");
 
-        (bit_size, Symbol::Lit(parsed_value))
+        let parsed_value = if negative {
+            -Number::from(magnitude)
+        } else {
+            Number::from(magnitude)
+        };
+
+        Ok((bit_size, Symbol::Lit(parsed_value)))
     }
 
-    fn demodulate_slice(slice: &[bool]) -> (usize, Symbol) {
+    fn demodulate_slice(slice: &[bool], offset: usize) -> Result<(usize, Symbol), DemodulateError> {
+        if slice.len() < 2 {
+            return Err(DemodulateError::UnexpectedEof {
+                offset: offset + slice.len(),
+                needed: 2 - slice.len(),
+            });
+        }
+
         let prefix = &slice[0..2];
 
         match prefix {
             [true, false] | [false, true] => {
-                let sign = if prefix == &modulate_constants::SIGN_POSITIVE {
-                    1
-                } else {
-                    -1
-                };
+                let negative = prefix == &modulate_constants::SIGN_NEGATIVE;
                 let slice = &slice[2..]; // move past prefix
 
-                let (size, symbol) = demodulate_number(sign, slice);
-                (size + 2, symbol)
+                let (size, symbol) = demodulate_number(negative, slice, offset + 2)?;
+                Ok((size + 2, symbol))
             }
             [true, true] => {
                 let slice = &slice[2..]; // move past prefix
 
-                let (first_size, first_symbol) = demodulate_slice(slice);
+                let (first_size, first_symbol) = demodulate_slice(slice, offset + 2)?;
 
                 let slice = &slice[first_size..]; // move past prefix
-                let (second_size, second_symbol) = demodulate_slice(slice);
+                let (second_size, second_symbol) =
+                    demodulate_slice(slice, offset + 2 + first_size)?;
 
-                (
-                    first_size + second_size,
+                Ok((
+                    first_size + second_size + 2,
                     Symbol::Pair(first_symbol.into(), second_symbol.into()),
-                )
+                ))
             }
-            [false, false] => (2, Symbol::Nil),
-            _ => unreachable!("Invalid modulation"),
+            [false, false] => Ok((2, Symbol::Nil)),
+            _ => Err(DemodulateError::InvalidPrefix {
+                offset,
+                bits: (prefix[0], prefix[1]),
+            }),
         }
     }
 
-    let slice = value.as_slice();
-    demodulate_slice(slice).1
+    let (size, symbol) = demodulate_slice(value.as_slice(), 0)?;
+
+    if size != value.len() {
+        return Err(DemodulateError::TrailingBits { offset: size });
+    }
+
+    Ok(symbol)
+}
+
+pub fn demodulate(value: Modulated) -> Symbol {
+    try_demodulate(&value).unwrap_or_else(|err| panic!("{}", err))
 }
 
-pub fn demodulate_string(s: &str) -> Symbol {
-    demodulate(s.bytes().map(|b| b == b'1').collect())
+pub fn demodulate_string(s: &str) -> Result<Symbol, DemodulateError> {
+    try_demodulate(&s.bytes().map(|b| b == b'1').collect())
 }
 
 pub fn modulate_to_string(symbol: &Symbol) -> String {
@@ -183,36 +311,44 @@ mod tests {
             s.bytes().map(|b| b == b'1').collect()
         }
 
-        assert_eq!(modulate_number(0), val("010"));
-        assert_eq!(modulate_number(1), val("01100001"));
-        assert_eq!(modulate_number(-1), val("10100001"));
-        assert_eq!(modulate_number(256), val("011110000100000000"));
+        assert_eq!(modulate_number(&Number::from(0)), val("010"));
+        assert_eq!(modulate_number(&Number::from(1)), val("01100001"));
+        assert_eq!(modulate_number(&Number::from(-1)), val("10100001"));
+        assert_eq!(modulate_number(&Number::from(256)), val("011110000100000000"));
 
-        assert_eq!(modulate(&Lit(0)), val("010"));
-        assert_eq!(modulate(&Lit(1)), val("01100001"));
-        assert_eq!(modulate(&Lit(-1)), val("10100001"));
-        assert_eq!(modulate(&Lit(256)), val("011110000100000000"));
+        assert_eq!(modulate(&Lit(0.into())), val("010"));
+        assert_eq!(modulate(&Lit(1.into())), val("01100001"));
+        assert_eq!(modulate(&Lit((-1).into())), val("10100001"));
+        assert_eq!(modulate(&Lit(256.into())), val("011110000100000000"));
     }
 
     #[test]
     fn test_demodulate_logic() {
-        assert_eq!(demodulate(modulate_number(0)), Lit(0));
-        assert_eq!(demodulate(modulate_number(1)), Lit(1));
-        assert_eq!(demodulate(modulate_number(-1)), Lit(-1));
-        assert_eq!(demodulate(modulate_number(256)), Lit(256));
-
-        use Symbol::Lit;
-        assert_eq!(demodulate(modulate_number(0)), Lit(0));
-        assert_eq!(demodulate(modulate_number(1)), Lit(1));
-        assert_eq!(demodulate(modulate_number(-1)), Lit(-1));
-        assert_eq!(demodulate(modulate_number(256)), Lit(256));
+        assert_eq!(demodulate(modulate_number(&Number::from(0))), Lit(0.into()));
+        assert_eq!(demodulate(modulate_number(&Number::from(1))), Lit(1.into()));
+        assert_eq!(
+            demodulate(modulate_number(&Number::from(-1))),
+            Lit((-1).into())
+        );
+        assert_eq!(
+            demodulate(modulate_number(&Number::from(256))),
+            Lit(256.into())
+        );
+    }
+
+    #[test]
+    fn test_demodulate_big() {
+        // exceeds i64, exercising the arbitrary-precision magnitude path.
+        let big: Number = "1000000000000000000000000000001".parse().unwrap();
+        assert_eq!(demodulate(modulate_number(&big)), Lit(big.clone()));
+        assert_eq!(demodulate(modulate_number(&-big.clone())), Lit(-big));
     }
 
     #[test]
     fn modulate_list_roundtrip() {
         assert_eq!(
-            modulate_to_string(&List(vec![Lit(1)])),
-            modulate_to_string(&Pair(Lit(1).into(), Nil.into()))
+            modulate_to_string(&List(vec![Lit(1.into())])),
+            modulate_to_string(&Pair(Lit(1.into()).into(), Nil.into()))
         );
     }
 
@@ -228,15 +364,32 @@ mod tests {
                 true, false, // Width = 4 * 1
                 false, false, true, false // Two
             ]),
-            Pair(Lit(1).into(), Lit(2).into())
+            Pair(Lit(1.into()).into(), Lit(2.into()).into())
         )
     }
 
+    #[test]
+    fn bytes_roundtrip() {
+        let bits = modulate(&Lit(256.into()));
+        let bytes = bits.to_bytes();
+        let restored = Modulated::from_bytes(&bytes, bits.len());
+        assert_eq!(restored, bits);
+        assert_eq!(demodulate(restored), Lit(256.into()));
+    }
+
+    #[test]
+    fn bytes_zero_padding() {
+        // 3 bits packs into a single byte, zero-padded in the low bits.
+        let bits = vec![false, true, false];
+        assert_eq!(bits.to_bytes(), vec![0b010_00000]);
+        assert_eq!(Modulated::from_bytes(&[0b010_00000], 3), bits);
+    }
+
     #[test]
     fn http_responses() {
         assert_eq!(
-            demodulate_string("1101000"),
-            Pair(Lit(0).into(), Nil.into())
+            demodulate_string("1101000").unwrap(),
+            Pair(Lit(0.into()).into(), Nil.into())
         );
 
         // 11 - list
@@ -248,10 +401,10 @@ mod tests {
         // 11110 - 4 width 16 bits (4*4)
         // 1111011100101010
         // 00 - Nil
-        let response = demodulate_string("1101100001110111110110100111100011000");
+        let response = demodulate_string("1101100001110111110110100111100011000").unwrap();
         assert_eq!(
             response,
-            Pair(Lit(1).into(), Pair(Lit(54214).into(), Nil.into()).into())
+            Pair(Lit(1.into()).into(), Pair(Lit(54214.into()).into(), Nil.into()).into())
         );
 
         let inc = super::super::eval_instructions(&[
@@ -264,4 +417,47 @@ mod tests {
 
         dbg!(modulate_to_string(&inc));
     }
+
+    #[test]
+    fn try_demodulate_reports_unexpected_eof() {
+        // A positive-number prefix with no width/magnitude bits behind it.
+        let bits: Modulated = "01".bytes().map(|b| b == b'1').collect();
+        assert_eq!(
+            try_demodulate(&bits),
+            Err(DemodulateError::UnexpectedEof {
+                offset: 2,
+                needed: 1
+            })
+        );
+    }
+
+    #[test]
+    fn try_demodulate_reports_trailing_bits() {
+        let mut bits: Modulated = "1101000".bytes().map(|b| b == b'1').collect();
+        bits.push(false);
+        assert_eq!(
+            try_demodulate(&bits),
+            Err(DemodulateError::TrailingBits { offset: 7 })
+        );
+    }
+
+    #[test]
+    fn try_modulate_reports_irreducible_symbol() {
+        assert_eq!(
+            try_modulate(&Add),
+            Err(ModulateError {
+                symbol: "add".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn try_modulate_names_nested_irreducible_symbol() {
+        assert_eq!(
+            try_modulate(&Pair(Lit(1.into()).into(), Add.into())),
+            Err(ModulateError {
+                symbol: "add".to_string()
+            })
+        );
+    }
 }
@@ -28,22 +28,22 @@ fn test_modulate() {
         Modulated(s.bytes().map(|b| b == b'1').collect())
     }
 
-    assert_eq!(eval_instructions(&[Ap, Mod, Lit(0)]), val("010"));
-    assert_eq!(eval_instructions(&[Ap, Mod, Lit(1)]), val("01100001"));
-    assert_eq!(eval_instructions(&[Ap, Mod, Lit(-1)]), val("10100001"));
+    assert_eq!(eval_instructions(&[Ap, Mod, Lit(0.into())]), val("010"));
+    assert_eq!(eval_instructions(&[Ap, Mod, Lit(1.into())]), val("01100001"));
+    assert_eq!(eval_instructions(&[Ap, Mod, Lit((-1).into())]), val("10100001"));
     assert_eq!(
-        eval_instructions(&[Ap, Mod, Lit(256)]),
+        eval_instructions(&[Ap, Mod, Lit(256.into())]),
         val("011110000100000000")
     );
 }
 
 #[test]
 fn test_demodulate() {
-    assert_eq!(eval_instructions(&[Ap, Dem, Ap, Mod, Lit(0)]), Lit(0));
-    assert_eq!(eval_instructions(&[Ap, Dem, Ap, Mod, Lit(1)]), Lit(1));
-    assert_eq!(eval_instructions(&[Ap, Dem, Ap, Mod, Lit(-1)]), Lit(-1));
-    assert_eq!(eval_instructions(&[Ap, Dem, Ap, Mod, Lit(256)]), Lit(256));
-    assert_eq!(eval_instructions(&[Ap, Dem, Ap, Mod, Lit(-256)]), Lit(-256));
+    assert_eq!(eval_instructions(&[Ap, Dem, Ap, Mod, Lit(0.into())]), Lit(0.into()));
+    assert_eq!(eval_instructions(&[Ap, Dem, Ap, Mod, Lit(1.into())]), Lit(1.into()));
+    assert_eq!(eval_instructions(&[Ap, Dem, Ap, Mod, Lit((-1).into())]), Lit((-1).into()));
+    assert_eq!(eval_instructions(&[Ap, Dem, Ap, Mod, Lit(256.into())]), Lit(256.into()));
+    assert_eq!(eval_instructions(&[Ap, Dem, Ap, Mod, Lit((-256).into())]), Lit((-256).into()));
 }
 
 #[test]
@@ -57,12 +57,12 @@ fn test_modulate_list() {
         Modulated(vec![true, true, false, false, false, false])
     );
     assert_eq!(
-        dbg!(eval_instructions(&[Ap, Mod, Ap, Ap, Cons, Lit(0), Nil])),
+        dbg!(eval_instructions(&[Ap, Mod, Ap, Ap, Cons, Lit(0.into()), Nil])),
         Modulated(vec![true, true, false, true, false, false, false])
     );
 
     assert_eq!(
-        dbg!(eval_instructions(&[Ap, Mod, Ap, Ap, Cons, Lit(1), Lit(2)])),
+        dbg!(eval_instructions(&[Ap, Mod, Ap, Ap, Cons, Lit(1.into()), Lit(2.into())])),
         Modulated(vec![
             true, true, false, true, true, false, false, false, false, true, false, true, true,
             false, false, false, true, false
@@ -76,11 +76,11 @@ fn test_modulate_list() {
             Ap,
             Ap,
             Cons,
-            Lit(1),
+            Lit(1.into()),
             Ap,
             Ap,
             Cons,
-            Lit(2),
+            Lit(2.into()),
             Nil
         ])),
         Modulated(vec![
@@ -96,32 +96,32 @@ fn test_modulate_list() {
 
 #[test]
 fn equality() {
-    let res = eval_instructions(&[Ap, Ap, Eq, Lit(1), Lit(1)]);
+    let res = eval_instructions(&[Ap, Ap, Eq, Lit(1.into()), Lit(1.into())]);
     assert_eq!(res, T);
 }
 
 #[test]
 fn inequality() {
-    let res = eval_instructions(&[Ap, Ap, Eq, Lit(1), Lit(2)]);
+    let res = eval_instructions(&[Ap, Ap, Eq, Lit(1.into()), Lit(2.into())]);
     assert_eq!(res, F);
 }
 
 #[test]
 fn cons() {
-    let res = eval_instructions(&[Ap, Ap, Cons, Lit(1), Lit(2)]);
-    assert_eq!(res, Pair(Lit(1).into(), Lit(2).into()));
+    let res = eval_instructions(&[Ap, Ap, Cons, Lit(1.into()), Lit(2.into())]);
+    assert_eq!(res, Pair(Lit(1.into()).into(), Lit(2.into()).into()));
 }
 
 #[test]
 fn car() {
-    let res = eval_instructions(&[Ap, Car, Ap, Ap, Cons, Lit(1), Lit(2)]);
-    assert_eq!(res, Lit(1))
+    let res = eval_instructions(&[Ap, Car, Ap, Ap, Cons, Lit(1.into()), Lit(2.into())]);
+    assert_eq!(res, Lit(1.into()))
 }
 
 #[test]
 fn cdr() {
-    let res = eval_instructions(&[Ap, Cdr, Ap, Ap, Cons, Lit(1), Lit(2)]);
-    assert_eq!(res, Lit(2))
+    let res = eval_instructions(&[Ap, Cdr, Ap, Ap, Cons, Lit(1.into()), Lit(2.into())]);
+    assert_eq!(res, Lit(2.into()))
 }
 
 #[test]
@@ -140,32 +140,32 @@ fn message5() {
     ap inc -3   =   -2
     */
 
-    let res = eval_instructions(&[Ap, Inc, Lit(0)]);
-    assert_eq!(res, Lit(1));
+    let res = eval_instructions(&[Ap, Inc, Lit(0.into())]);
+    assert_eq!(res, Lit(1.into()));
 
-    let res = eval_instructions(&[Ap, Inc, Lit(1)]);
-    assert_eq!(res, Lit(2));
+    let res = eval_instructions(&[Ap, Inc, Lit(1.into())]);
+    assert_eq!(res, Lit(2.into()));
 
-    let res = eval_instructions(&[Ap, Inc, Lit(2)]);
-    assert_eq!(res, Lit(3));
+    let res = eval_instructions(&[Ap, Inc, Lit(2.into())]);
+    assert_eq!(res, Lit(3.into()));
 
-    let res = eval_instructions(&[Ap, Inc, Lit(3)]);
-    assert_eq!(res, Lit(4));
+    let res = eval_instructions(&[Ap, Inc, Lit(3.into())]);
+    assert_eq!(res, Lit(4.into()));
 
-    let res = eval_instructions(&[Ap, Inc, Lit(300)]);
-    assert_eq!(res, Lit(301));
+    let res = eval_instructions(&[Ap, Inc, Lit(300.into())]);
+    assert_eq!(res, Lit(301.into()));
 
-    let res = eval_instructions(&[Ap, Inc, Lit(301)]);
-    assert_eq!(res, Lit(302));
+    let res = eval_instructions(&[Ap, Inc, Lit(301.into())]);
+    assert_eq!(res, Lit(302.into()));
 
-    let res = eval_instructions(&[Ap, Inc, Lit(-1)]);
-    assert_eq!(res, Lit(0));
+    let res = eval_instructions(&[Ap, Inc, Lit((-1).into())]);
+    assert_eq!(res, Lit(0.into()));
 
-    let res = eval_instructions(&[Ap, Inc, Lit(-2)]);
-    assert_eq!(res, Lit(-1));
+    let res = eval_instructions(&[Ap, Inc, Lit((-2).into())]);
+    assert_eq!(res, Lit((-1).into()));
 
-    let res = eval_instructions(&[Ap, Inc, Lit(-3)]);
-    assert_eq!(res, Lit(-2));
+    let res = eval_instructions(&[Ap, Inc, Lit((-3).into())]);
+    assert_eq!(res, Lit((-2).into()));
 }
 
 #[test]
@@ -181,44 +181,44 @@ fn message9() {
     ap ap mul x0 1   =   x0
     */
 
-    let res = eval_instructions(&[Ap, Ap, Mul, Lit(4), Lit(2)]);
-    assert_eq!(res, Lit(8));
+    let res = eval_instructions(&[Ap, Ap, Mul, Lit(4.into()), Lit(2.into())]);
+    assert_eq!(res, Lit(8.into()));
 
-    let res = eval_instructions(&[Ap, Ap, Mul, Lit(3), Lit(4)]);
-    assert_eq!(res, Lit(12));
+    let res = eval_instructions(&[Ap, Ap, Mul, Lit(3.into()), Lit(4.into())]);
+    assert_eq!(res, Lit(12.into()));
 
-    let res = eval_instructions(&[Ap, Ap, Mul, Lit(3), Lit(-2)]);
-    assert_eq!(res, Lit(-6));
+    let res = eval_instructions(&[Ap, Ap, Mul, Lit(3.into()), Lit((-2).into())]);
+    assert_eq!(res, Lit((-6).into()));
 
     let res = eval(
         &[Ap, Ap, Mul, Var(0.into()), Var(1.into())],
         &mut vec![
-            (Identifier::id(0), vec![Lit(42).into()]),
-            (Identifier::id(1), vec![Lit(7).into()]),
+            (Identifier::id(0), vec![Lit(42.into()).into()]),
+            (Identifier::id(1), vec![Lit(7.into()).into()]),
         ]
         .into_iter()
         .collect(),
     );
 
-    assert_eq!(res.deref().clone(), Lit(294));
+    assert_eq!(res.deref().clone(), Lit(294.into()));
 
     let res = eval(
-        &[Ap, Ap, Mul, Var(0.into()), Lit(0)],
-        &mut vec![(Identifier::id(0), vec![Lit(42).into()])]
+        &[Ap, Ap, Mul, Var(0.into()), Lit(0.into())],
+        &mut vec![(Identifier::id(0), vec![Lit(42.into()).into()])]
             .into_iter()
             .collect(),
     );
 
-    assert_eq!(res.deref().clone(), Lit(0));
+    assert_eq!(res.deref().clone(), Lit(0.into()));
 
     let res = eval(
-        &[Ap, Ap, Mul, Var(0.into()), Lit(1)],
-        &mut vec![(Identifier::id(0), vec![Lit(42).into()])]
+        &[Ap, Ap, Mul, Var(0.into()), Lit(1.into())],
+        &mut vec![(Identifier::id(0), vec![Lit(42.into()).into()])]
             .into_iter()
             .collect(),
     );
 
-    assert_eq!(res.deref().clone(), Lit(42));
+    assert_eq!(res.deref().clone(), Lit(42.into()));
 }
 
 #[test]
@@ -238,38 +238,38 @@ fn message10() {
     ap ap div x0 1   =   x0
     */
 
-    let res = eval_instructions(&[Ap, Ap, Div, Lit(4), Lit(2)]);
-    assert_eq!(res, Lit(2));
+    let res = eval_instructions(&[Ap, Ap, Div, Lit(4.into()), Lit(2.into())]);
+    assert_eq!(res, Lit(2.into()));
 
-    let res = eval_instructions(&[Ap, Ap, Div, Lit(4), Lit(3)]);
-    assert_eq!(res, Lit(1));
+    let res = eval_instructions(&[Ap, Ap, Div, Lit(4.into()), Lit(3.into())]);
+    assert_eq!(res, Lit(1.into()));
 
-    let res = eval_instructions(&[Ap, Ap, Div, Lit(4), Lit(4)]);
-    assert_eq!(res, Lit(1));
+    let res = eval_instructions(&[Ap, Ap, Div, Lit(4.into()), Lit(4.into())]);
+    assert_eq!(res, Lit(1.into()));
 
-    let res = eval_instructions(&[Ap, Ap, Div, Lit(4), Lit(5)]);
-    assert_eq!(res, Lit(0));
+    let res = eval_instructions(&[Ap, Ap, Div, Lit(4.into()), Lit(5.into())]);
+    assert_eq!(res, Lit(0.into()));
 
-    let res = eval_instructions(&[Ap, Ap, Div, Lit(5), Lit(2)]);
-    assert_eq!(res, Lit(2));
+    let res = eval_instructions(&[Ap, Ap, Div, Lit(5.into()), Lit(2.into())]);
+    assert_eq!(res, Lit(2.into()));
 
-    let res = eval_instructions(&[Ap, Ap, Div, Lit(6), Lit(-2)]);
-    assert_eq!(res, Lit(-3));
+    let res = eval_instructions(&[Ap, Ap, Div, Lit(6.into()), Lit((-2).into())]);
+    assert_eq!(res, Lit((-3).into()));
 
-    let res = eval_instructions(&[Ap, Ap, Div, Lit(5), Lit(-3)]);
-    assert_eq!(res, Lit(-1));
+    let res = eval_instructions(&[Ap, Ap, Div, Lit(5.into()), Lit((-3).into())]);
+    assert_eq!(res, Lit((-1).into()));
 
-    let res = eval_instructions(&[Ap, Ap, Div, Lit(-5), Lit(-3)]);
-    assert_eq!(res, Lit(1));
+    let res = eval_instructions(&[Ap, Ap, Div, Lit((-5).into()), Lit((-3).into())]);
+    assert_eq!(res, Lit(1.into()));
 
     let res = eval(
-        &[Ap, Ap, Div, Var(0.into()), Lit(1)],
-        &mut vec![(Identifier::id(0), vec![Lit(42).into()])]
+        &[Ap, Ap, Div, Var(0.into()), Lit(1.into())],
+        &mut vec![(Identifier::id(0), vec![Lit(42.into()).into()])]
             .into_iter()
             .collect(),
     );
 
-    assert_eq!(res.clone(), Lit(42).into());
+    assert_eq!(res.clone(), Lit(42.into()).into());
 }
 
 #[test]
@@ -299,46 +299,46 @@ fn message12() {
     ap ap lt -21 -20   =   t
     */
 
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(0), Lit(-1)]), F);
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(0), Lit(0)]), F);
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(0), Lit(1)]), T);
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(0), Lit(2)]), T);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(0.into()), Lit((-1).into())]), F);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(0.into()), Lit(0.into())]), F);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(0.into()), Lit(1.into())]), T);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(0.into()), Lit(2.into())]), T);
 
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(1), Lit(0)]), F);
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(1), Lit(1)]), F);
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(1), Lit(2)]), T);
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(1), Lit(3)]), T);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(1.into()), Lit(0.into())]), F);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(1.into()), Lit(1.into())]), F);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(1.into()), Lit(2.into())]), T);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(1.into()), Lit(3.into())]), T);
 
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(2), Lit(1)]), F);
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(2), Lit(2)]), F);
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(2), Lit(3)]), T);
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(2), Lit(4)]), T);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(2.into()), Lit(1.into())]), F);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(2.into()), Lit(2.into())]), F);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(2.into()), Lit(3.into())]), T);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(2.into()), Lit(4.into())]), T);
 
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(19), Lit(20)]), T);
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(20), Lit(20)]), F);
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(21), Lit(20)]), F);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(19.into()), Lit(20.into())]), T);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(20.into()), Lit(20.into())]), F);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(21.into()), Lit(20.into())]), F);
 
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(-19), Lit(-20)]), F);
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(-20), Lit(-20)]), F);
-    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit(-21), Lit(-20)]), T);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit((-19).into()), Lit((-20).into())]), F);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit((-20).into()), Lit((-20).into())]), F);
+    assert_eq!(eval_instructions(&[Ap, Ap, Lt, Lit((-21).into()), Lit((-20).into())]), T);
 }
 
 #[test]
 fn message16() {
-    let res = eval_instructions(&[Ap, Neg, Lit(0)]);
-    assert_eq!(res, Lit(0));
+    let res = eval_instructions(&[Ap, Neg, Lit(0.into())]);
+    assert_eq!(res, Lit(0.into()));
 
-    let res = eval_instructions(&[Ap, Neg, Lit(1)]);
-    assert_eq!(res, Lit(-1));
+    let res = eval_instructions(&[Ap, Neg, Lit(1.into())]);
+    assert_eq!(res, Lit((-1).into()));
 
-    let res = eval_instructions(&[Ap, Neg, Lit(-1)]);
-    assert_eq!(res, Lit(1));
+    let res = eval_instructions(&[Ap, Neg, Lit((-1).into())]);
+    assert_eq!(res, Lit(1.into()));
 
-    let res = eval_instructions(&[Ap, Neg, Lit(2)]);
-    assert_eq!(res, Lit(-2));
+    let res = eval_instructions(&[Ap, Neg, Lit(2.into())]);
+    assert_eq!(res, Lit((-2).into()));
 
-    let res = eval_instructions(&[Ap, Neg, Lit(-2)]);
-    assert_eq!(res, Lit(2));
+    let res = eval_instructions(&[Ap, Neg, Lit((-2).into())]);
+    assert_eq!(res, Lit(2.into()));
 }
 
 #[test]
@@ -350,32 +350,32 @@ fn message18() {
     */
 
     // let res = eval(
-    //     &[Ap, Ap, Ap, S, Div, Var(0), Lit(1)],
-    //     &mut vec![(0, Lit(42))].into_iter().collect(),
+    //     &[Ap, Ap, Ap, S, Div, Var(0), Lit(1.into())],
+    //     &mut vec![(0, Lit(42.into()))].into_iter().collect(),
     // );
 
-    let res = eval_instructions(&[Ap, Ap, Ap, S, Add, Inc, Lit(1)]);
-    assert_eq!(res, Lit(3));
+    let res = eval_instructions(&[Ap, Ap, Ap, S, Add, Inc, Lit(1.into())]);
+    assert_eq!(res, Lit(3.into()));
 
-    let res = eval_instructions(&[Ap, Ap, Ap, S, Mul, Ap, Add, Lit(1), Lit(6)]);
-    assert_eq!(res, Lit(42));
+    let res = eval_instructions(&[Ap, Ap, Ap, S, Mul, Ap, Add, Lit(1.into()), Lit(6.into())]);
+    assert_eq!(res, Lit(42.into()));
 }
 
 #[test]
 fn message19() {
-    let res = eval_instructions(&[Ap, Ap, Ap, C, Add, Lit(1), Lit(2)]);
-    assert_eq!(res, Lit(3));
+    let res = eval_instructions(&[Ap, Ap, Ap, C, Add, Lit(1.into()), Lit(2.into())]);
+    assert_eq!(res, Lit(3.into()));
 }
 
 #[test]
 fn message20() {
     let res = eval(
         &[Ap, Ap, Ap, B, Inc, Dec, Var(1.into())],
-        &mut vec![(Identifier::id(1), vec![Lit(42).into()])]
+        &mut vec![(Identifier::id(1), vec![Lit(42.into()).into()])]
             .into_iter()
             .collect(),
     );
-    assert_eq!(res.deref().clone(), Lit(42));
+    assert_eq!(res.deref().clone(), Lit(42.into()));
 }
 
 #[test]
@@ -388,17 +388,17 @@ fn message21() {
     ap ap t ap inc 5 t   =   6
     */
 
-    let res = eval_instructions(&[Ap, Ap, T, Lit(1), Lit(5)]);
-    assert_eq!(res, Lit(1));
+    let res = eval_instructions(&[Ap, Ap, T, Lit(1.into()), Lit(5.into())]);
+    assert_eq!(res, Lit(1.into()));
 
-    let res = eval_instructions(&[Ap, Ap, T, T, Lit(5)]);
+    let res = eval_instructions(&[Ap, Ap, T, T, Lit(5.into())]);
     assert_eq!(res, T);
 
-    let res = eval_instructions(&[Ap, Ap, T, T, Ap, Inc, Lit(5)]);
+    let res = eval_instructions(&[Ap, Ap, T, T, Ap, Inc, Lit(5.into())]);
     assert_eq!(res, T);
 
-    let res = eval_instructions(&[Ap, Ap, T, Ap, Inc, Lit(5), T]);
-    assert_eq!(res, Lit(6));
+    let res = eval_instructions(&[Ap, Ap, T, Ap, Inc, Lit(5.into()), T]);
+    assert_eq!(res, Lit(6.into()));
 }
 
 #[test]
@@ -406,38 +406,38 @@ fn message22() {
     let res = eval(
         &[Ap, Ap, F, Var(1.into()), Var(2.into())],
         &mut vec![
-            (Identifier::id(1), vec![Lit(3).into()]),
-            (Identifier::id(2), vec![Lit(4).into()]),
+            (Identifier::id(1), vec![Lit(3.into()).into()]),
+            (Identifier::id(2), vec![Lit(4.into()).into()]),
         ]
         .into_iter()
         .collect(),
     );
 
-    assert_eq!(res.deref().clone(), Lit(4))
+    assert_eq!(res.deref().clone(), Lit(4.into()))
 }
 
 #[test]
 fn message23() {
-    let res = eval_instructions(&[Ap, Pwr2, Lit(2)]);
-    assert_eq!(res, Lit(4));
+    let res = eval_instructions(&[Ap, Pwr2, Lit(2.into())]);
+    assert_eq!(res, Lit(4.into()));
 
-    let res = eval_instructions(&[Ap, Pwr2, Lit(3)]);
-    assert_eq!(res, Lit(8));
+    let res = eval_instructions(&[Ap, Pwr2, Lit(3.into())]);
+    assert_eq!(res, Lit(8.into()));
 
-    let res = eval_instructions(&[Ap, Pwr2, Lit(4)]);
-    assert_eq!(res, Lit(16));
+    let res = eval_instructions(&[Ap, Pwr2, Lit(4.into())]);
+    assert_eq!(res, Lit(16.into()));
 
-    let res = eval_instructions(&[Ap, Pwr2, Lit(5)]);
-    assert_eq!(res, Lit(32));
+    let res = eval_instructions(&[Ap, Pwr2, Lit(5.into())]);
+    assert_eq!(res, Lit(32.into()));
 
-    let res = eval_instructions(&[Ap, Pwr2, Lit(6)]);
-    assert_eq!(res, Lit(64));
+    let res = eval_instructions(&[Ap, Pwr2, Lit(6.into())]);
+    assert_eq!(res, Lit(64.into()));
 
-    let res = eval_instructions(&[Ap, Pwr2, Lit(7)]);
-    assert_eq!(res, Lit(128));
+    let res = eval_instructions(&[Ap, Pwr2, Lit(7.into())]);
+    assert_eq!(res, Lit(128.into()));
 
-    let res = eval_instructions(&[Ap, Pwr2, Lit(8)]);
-    assert_eq!(res, Lit(256));
+    let res = eval_instructions(&[Ap, Pwr2, Lit(8.into())]);
+    assert_eq!(res, Lit(256.into()));
 }
 
 #[test]
@@ -452,15 +452,15 @@ fn message24() {
 
     let res = eval(
         &[Ap, I, Var(0.into())],
-        &mut vec![(Identifier::id(0), vec![Lit(42).into()])]
+        &mut vec![(Identifier::id(0), vec![Lit(42.into()).into()])]
             .into_iter()
             .collect(),
     );
 
-    assert_eq!(res.deref().clone(), Lit(42));
+    assert_eq!(res.deref().clone(), Lit(42.into()));
 
-    let res = eval_instructions(&[Ap, I, Lit(1)]);
-    assert_eq!(res, Lit(1));
+    let res = eval_instructions(&[Ap, I, Lit(1.into())]);
+    assert_eq!(res, Lit(1.into()));
 
     let res = eval_instructions(&[Ap, I, I]);
     assert_eq!(res, I);
@@ -468,12 +468,12 @@ fn message24() {
     let res = eval_instructions(&[Ap, I, Add]);
     assert_eq!(res, Add);
 
-    let res = eval_instructions(&[Ap, I, Ap, Add, Lit(1)]);
+    let res = eval_instructions(&[Ap, I, Ap, Add, Lit(1.into())]);
     assert_eq!(
         res,
         Closure {
             body: Add.into(),
-            captured_arg: Lit(1).into()
+            captured_arg: Lit(1.into()).into()
         }
     )
 }
@@ -486,33 +486,33 @@ fn message28() {
 
 #[test]
 fn message30() {
-    let res = eval_instructions(&[Ap, Car, List(vec![Lit(1)])]);
-    assert_eq!(res, Lit(1));
+    let res = eval_instructions(&[Ap, Car, List(vec![Lit(1.into())])]);
+    assert_eq!(res, Lit(1.into()));
 
-    let res = eval_instructions(&[Ap, Car, List(vec![Lit(3), Lit(2), Lit(1)])]);
-    assert_eq!(res, Lit(3));
+    let res = eval_instructions(&[Ap, Car, List(vec![Lit(3.into()), Lit(2.into()), Lit(1.into())])]);
+    assert_eq!(res, Lit(3.into()));
 
-    let res = eval_instructions(&[Ap, Cdr, List(vec![Lit(3), Lit(2), Lit(1)])]);
-    assert_eq!(res, List(vec![Lit(2), Lit(1)]).canonicalize());
+    let res = eval_instructions(&[Ap, Cdr, List(vec![Lit(3.into()), Lit(2.into()), Lit(1.into())])]);
+    assert_eq!(res, List(vec![Lit(2.into()), Lit(1.into())]).canonicalize());
 }
 
 #[test]
 fn message33() {
-    let res = eval_instructions(&[Ap, Ap, Checkerboard, Lit(4), Lit(4)]);
+    let res = eval_instructions(&[Ap, Ap, Checkerboard, Lit(4.into()), Lit(4.into())]);
     dbg!(&res);
 
     assert_eq!(
         res,
         List(vec![
-            Pair(Lit(0).into(), Lit(0).into()),
-            Pair(Lit(0).into(), Lit(2).into()),
-            Pair(Lit(0).into(), Lit(4).into()),
-            Pair(Lit(2).into(), Lit(0).into()),
-            Pair(Lit(2).into(), Lit(2).into()),
-            Pair(Lit(2).into(), Lit(4).into()),
-            Pair(Lit(4).into(), Lit(0).into()),
-            Pair(Lit(4).into(), Lit(2).into()),
-            Pair(Lit(4).into(), Lit(4).into())
+            Pair(Lit(0.into()).into(), Lit(0.into()).into()),
+            Pair(Lit(0.into()).into(), Lit(2.into()).into()),
+            Pair(Lit(0.into()).into(), Lit(4.into()).into()),
+            Pair(Lit(2.into()).into(), Lit(0.into()).into()),
+            Pair(Lit(2.into()).into(), Lit(2.into()).into()),
+            Pair(Lit(2.into()).into(), Lit(4.into()).into()),
+            Pair(Lit(4.into()).into(), Lit(0.into()).into()),
+            Pair(Lit(4.into()).into(), Lit(2.into()).into()),
+            Pair(Lit(4.into()).into(), Lit(4.into()).into())
         ])
         .canonicalize()
     )
@@ -521,13 +521,26 @@ fn message33() {
 #[test]
 fn message37() {
     let res = eval(
-        &[Ap, Ap, Ap, If0, Lit(0), Var(1.into()), Lit(2)],
-        &mut vec![(Identifier::id(1), vec![Lit(42).into()])]
+        &[Ap, Ap, Ap, If0, Lit(0.into()), Var(1.into()), Lit(2.into())],
+        &mut vec![(Identifier::id(1), vec![Lit(42.into()).into()])]
             .into_iter()
             .collect(),
     );
-    assert_eq!(res.deref(), &Lit(42));
+    assert_eq!(res.deref(), &Lit(42.into()));
 
-    let res = eval_instructions(&[Ap, Ap, Ap, If0, Lit(1), Lit(0), Lit(1)]);
-    assert_eq!(res, Symbol::Lit(1));
+    let res = eval_instructions(&[Ap, Ap, Ap, If0, Lit(1.into()), Lit(0.into()), Lit(1.into())]);
+    assert_eq!(res, Symbol::Lit(1.into()));
+}
+
+#[test]
+fn hash_consing_shares_structurally_equal_cells() {
+    let a: SymbolCell = Pair(Lit(1.into()).into(), Lit(2.into()).into()).into();
+    let b: SymbolCell = Pair(Lit(1.into()).into(), Lit(2.into()).into()).into();
+
+    // Built independently, but interning guarantees they share one allocation.
+    assert_eq!(a, b);
+    assert_eq!(a.thunk_key(), b.thunk_key());
+
+    let c: SymbolCell = Pair(Lit(1.into()).into(), Lit(3.into()).into()).into();
+    assert_ne!(a, c);
 }
@@ -1,21 +1,130 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
 use hyper::StatusCode;
 use reqwest;
 use reqwest::{Body, Error, Response};
 
+use crate::ast::{modulate_to_string, DemodulateError, Symbol};
+
 #[derive(Eq, PartialEq, Ord, PartialOrd, Clone)]
 struct ResponseId(String);
 
-struct Client {
+/// Backoff before retry `attempt` (1-indexed: the first retry is `attempt ==
+/// 1`): 100ms, 200ms, 400ms, ... Shared by [`Client::send_and_confirm`] and
+/// [`crate::stack_interpreter::HttpEffects`]'s send-and-confirm loop, so the
+/// two transports can't drift apart.
+pub(crate) fn backoff_delay(attempt: u32) -> Duration {
+    Duration::from_millis(100 * 2u64.pow(attempt - 1))
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    Http(Error),
+    UnexpectedStatus(StatusCode),
+    Demodulate(DemodulateError),
+    RetriesExhausted,
+}
+
+/// A transport that can POST an already-modulated payload to the alien
+/// proxy, blocking the calling thread until it answers.
+/// [`crate::stack_interpreter::HttpEffects`] is the sync implementation;
+/// [`AsyncClient`] is the counterpart for callers already on an async
+/// runtime.
+pub trait SyncClient {
+    type Error;
+
+    fn send(&self, content: String) -> Result<String, Self::Error>;
+}
+
+/// Async counterpart to [`SyncClient`]: POSTs an already-modulated payload
+/// to the alien proxy and hands back the raw response, without interpreting
+/// its status or demodulating its body. [`Client::send_and_confirm`] is
+/// built on top of this to add retries and demodulation.
+pub trait AsyncClient {
+    type Error;
+
+    fn send(
+        &self,
+        content: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Response, Self::Error>> + Send + '_>>;
+}
+
+pub struct Client {
     server_url: String,
     api_key: String,
+    max_attempts: u32,
 }
 
 impl Client {
-    fn new(server_url: &str, api_key: &str) -> Client {
+    pub fn new(server_url: &str, api_key: &str) -> Client {
         Client {
             server_url: server_url.trim_end_matches("/").to_string(),
             api_key: api_key.to_string(),
+            max_attempts: 5,
+        }
+    }
+
+    /// Same as [`Client::new`], but with an explicit ceiling on how many
+    /// attempts in total (the first try included) [`Client::send_and_confirm`]
+    /// makes, instead of the default of 5.
+    pub fn with_max_attempts(server_url: &str, api_key: &str, max_attempts: u32) -> Client {
+        Client {
+            max_attempts,
+            ..Client::new(server_url, api_key)
+        }
+    }
+
+    /// Modulates `symbol`, POSTs it to the alien proxy, and demodulates the
+    /// response body back into a `Symbol` - the wire-level step of the
+    /// `interact` game loop. Unlike [`Client::send_and_confirm`], a single
+    /// connection error or non-200 response fails the whole call.
+    pub async fn send_symbol(&self, symbol: &Symbol) -> Result<Symbol, ClientError> {
+        let body = modulate_to_string(symbol);
+
+        let response = AsyncClient::send(self, body)
+            .await
+            .map_err(ClientError::Http)?;
+
+        match response.status() {
+            StatusCode::OK => {
+                let text = response.text().await.map_err(ClientError::Http)?;
+                crate::ast::demodulate_string(&text).map_err(ClientError::Demodulate)
+            }
+            status => Err(ClientError::UnexpectedStatus(status)),
+        }
+    }
+
+    /// Modulates `symbol`, POSTs it to the alien proxy, and demodulates the
+    /// reply, retrying connection errors and 5xx responses with exponential
+    /// backoff until `max_attempts` attempts in total have been made. This
+    /// is what the interactive game loop should call instead of
+    /// [`Client::send_symbol`]: a transient drop mid-match is retried rather
+    /// than killing the bot.
+    pub async fn send_and_confirm(&self, symbol: &Symbol) -> Result<Symbol, ClientError> {
+        let body = modulate_to_string(symbol);
+        let mut last_err = None;
+
+        for attempt in 0..self.max_attempts {
+            if attempt > 0 {
+                tokio::time::sleep(backoff_delay(attempt)).await;
+            }
+
+            match AsyncClient::send(self, body.clone()).await {
+                Ok(response) if response.status().is_success() => {
+                    let text = response.text().await.map_err(ClientError::Http)?;
+                    return crate::ast::demodulate_string(&text).map_err(ClientError::Demodulate);
+                }
+                Ok(response) if response.status().is_server_error() => {
+                    last_err = Some(ClientError::UnexpectedStatus(response.status()));
+                }
+                Ok(response) => return Err(ClientError::UnexpectedStatus(response.status())),
+                Err(err) => last_err = Some(ClientError::Http(err)),
+            }
         }
+
+        Err(last_err.unwrap_or(ClientError::RetriesExhausted))
     }
 
     async fn get_response(&self, response_id: ResponseId) -> Result<Response, Error> {
@@ -31,16 +140,6 @@ impl Client {
             .await
     }
 
-    async fn send<T: Into<String>>(&self, content: T) -> Result<Response, Error> {
-        reqwest::Client::builder()
-            .build()?
-            .post(&format!("{url}/aliens/send", url = self.server_url))
-            .body(Body::from(content.into()))
-            .query(&[("apiKey", self.api_key.clone())])
-            .send()
-            .await
-    }
-
     async fn echo<T: Into<String>>(&self, content: T) -> Result<Response, Error> {
         reqwest::Client::builder()
             .build()?
@@ -52,5 +151,24 @@ impl Client {
     }
 }
 
+impl AsyncClient for Client {
+    type Error = Error;
+
+    fn send(
+        &self,
+        content: String,
+    ) -> Pin<Box<dyn Future<Output = Result<Response, Error>> + Send + '_>> {
+        Box::pin(async move {
+            reqwest::Client::builder()
+                .build()?
+                .post(&format!("{url}/aliens/send", url = self.server_url))
+                .body(Body::from(content))
+                .query(&[("apiKey", self.api_key.clone())])
+                .send()
+                .await
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,5 +1,5 @@
 use crate::ast::{modulate_to_string, Symbol};
-use crate::client::Client;
+use crate::client::{AsyncClient, Client};
 use std::fs::read_to_string;
 
 #[tokio::test]
@@ -17,12 +17,12 @@ async fn send_list() {
 
     use Symbol::*;
 
-    let symbol = Pair(Lit(1).into(), Nil.into());
+    let symbol = Pair(Lit(1.into()).into(), Nil.into());
 
     let response = client.send(modulate_to_string(&symbol)).await.unwrap();
     dbg!(dbg!(response).text().await.unwrap());
 
-    let symbol = Pair(Lit(2).into(), Pair(Lit(54214).into(), Nil.into()).into());
+    let symbol = Pair(Lit(2.into()).into(), Pair(Lit(54214.into()).into(), Nil.into()).into());
 
     let response = client
         .send(dbg!(modulate_to_string(&symbol)))
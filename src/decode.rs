@@ -1,8 +1,259 @@
-use hound;
+use std::fs::File;
+use std::io::BufWriter;
+
+use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
+
+use crate::ast::modulations::{modulate, try_demodulate, DemodulateError, Modulated};
 use crate::ast::Symbol;
 
-fn decode(filename: &str) -> hound::Result<Symbol> {
-    let reader = hound::WavReader::open(filename)?;
-    println!("duration: {0}", reader.duration());
-    panic!();
-}
\ No newline at end of file
+/// Clock rate used by [`encode`]; [`decode`] recovers it automatically via
+/// [`detect_samples_per_bit`] instead of the two sides having to agree on it
+/// out of band.
+const SAMPLES_PER_BIT: usize = 8;
+
+/// Why [`decode`] failed: either the WAV file itself couldn't be read, or
+/// its recovered bitstream didn't demodulate into a valid `Symbol`.
+#[derive(Debug)]
+pub enum DecodeError {
+    Wav(hound::Error),
+    Demodulate(DemodulateError),
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DecodeError::Wav(err) => write!(f, "{}", err),
+            DecodeError::Demodulate(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl From<hound::Error> for DecodeError {
+    fn from(err: hound::Error) -> Self {
+        DecodeError::Wav(err)
+    }
+}
+
+/// Decodes a modulated galaxy transmission recorded as a WAV audio file. The
+/// modulation format in `ast::modulations` is a high/low signal encoding, so
+/// recovering it is a matter of bit-clock recovery: trim any leading/trailing
+/// silence, threshold each sample to a high/low bit, recover the clock (the
+/// number of samples held per bit, auto-detected as the shortest constant run
+/// unless `samples_per_bit` is given), and majority-vote each
+/// clock window into a single bit before handing the result to
+/// `try_demodulate`. A corrupt or truncated recording reports a
+/// [`DecodeError`] rather than aborting the process.
+pub fn decode(filename: &str, samples_per_bit: Option<usize>) -> Result<Symbol, DecodeError> {
+    let mut reader = WavReader::open(filename)?;
+    let amplitudes = read_amplitudes(&mut reader)?;
+    let amplitudes = trim_silence(&amplitudes);
+
+    if amplitudes.is_empty() {
+        return try_demodulate(&Modulated::new()).map_err(DecodeError::Demodulate);
+    }
+
+    let highs: Vec<bool> = amplitudes.iter().map(|&amplitude| amplitude > 0.0).collect();
+    let samples_per_bit = samples_per_bit.unwrap_or_else(|| detect_samples_per_bit(&highs));
+
+    // `chunks` yields a short final window when the recording's sample count
+    // isn't an exact multiple of `samples_per_bit` (e.g. trailing
+    // zero-padding); the majority vote below handles that window the same as
+    // any other.
+    let bits: Modulated = highs
+        .chunks(samples_per_bit)
+        .map(|window| {
+            let high_count = window.iter().filter(|&&high| high).count();
+            high_count * 2 >= window.len()
+        })
+        .collect();
+
+    try_demodulate(&bits).map_err(DecodeError::Demodulate)
+}
+
+/// Renders `modulate(symbol)` as a WAV tone stream: each bit becomes a run of
+/// `SAMPLES_PER_BIT` full-amplitude samples, high for a `1` bit and low for a
+/// `0` bit, so a `decode` of the result round-trips back to `symbol`.
+pub fn encode(filename: &str, symbol: &Symbol, spec: WavSpec) -> hound::Result<()> {
+    let mut writer = WavWriter::create(filename, spec)?;
+
+    for bit in modulate(symbol) {
+        let amplitude = if bit { 1.0 } else { -1.0 };
+        for _ in 0..SAMPLES_PER_BIT {
+            write_sample(&mut writer, spec, amplitude)?;
+        }
+    }
+
+    writer.finalize()
+}
+
+fn write_sample(
+    writer: &mut WavWriter<BufWriter<File>>,
+    spec: WavSpec,
+    amplitude: f32,
+) -> hound::Result<()> {
+    match spec.sample_format {
+        SampleFormat::Float => writer.write_sample(amplitude),
+        SampleFormat::Int => {
+            let max = (1i64 << (spec.bits_per_sample - 1)) - 1;
+            writer.write_sample((amplitude * max as f32).round() as i32)
+        }
+    }
+}
+
+/// Reads every sample as a signed amplitude in `[-1.0, 1.0]`, regardless of
+/// the WAV file's underlying sample format.
+fn read_amplitudes(reader: &mut WavReader<std::io::BufReader<File>>) -> hound::Result<Vec<f32>> {
+    let spec = reader.spec();
+
+    match spec.sample_format {
+        SampleFormat::Float => reader.samples::<f32>().collect(),
+        SampleFormat::Int => {
+            let max = (1i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|sample| sample.map(|s| s as f32 / max))
+                .collect()
+        }
+    }
+}
+
+/// Drops leading/trailing samples near zero amplitude, which is how a
+/// recording typically pads the actual transmission with silence.
+fn trim_silence(amplitudes: &[f32]) -> &[f32] {
+    const SILENCE_THRESHOLD: f32 = 0.05;
+
+    let start = amplitudes
+        .iter()
+        .position(|&a| a.abs() > SILENCE_THRESHOLD)
+        .unwrap_or(amplitudes.len());
+    let end = amplitudes
+        .iter()
+        .rposition(|&a| a.abs() > SILENCE_THRESHOLD)
+        .map(|i| i + 1)
+        .unwrap_or(start);
+
+    &amplitudes[start..end]
+}
+
+/// Auto-detects the samples-per-bit clock as the shortest constant run in
+/// the signal. The *first* run isn't reliable: every modulated `Pair`/list
+/// starts with the two-bit `11` tag and every `Nil` with `00`, so the
+/// opening run is frequently 2 (or more) bits wide, and taking it at face
+/// value would overestimate the clock. A lone `1` or `0` bit somewhere in
+/// the stream (virtually guaranteed outside of a handful of trivial
+/// symbols) pins down the true single-bit width. The clock is a whole number
+/// of samples: a recording whose true bit width is fractional is not
+/// resampled, so its windows fall out of step with the bits over a long
+/// transmission. A short final window is handled by `decode`'s majority vote.
+fn detect_samples_per_bit(highs: &[bool]) -> usize {
+    let mut shortest = highs.len();
+    let mut run_start = 0;
+
+    for i in 1..=highs.len() {
+        if i == highs.len() || highs[i] != highs[run_start] {
+            shortest = shortest.min(i - run_start);
+            run_start = i;
+        }
+    }
+
+    shortest.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::Number;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn wav_spec() -> WavSpec {
+        WavSpec {
+            channels: 1,
+            sample_rate: 44100,
+            bits_per_sample: 16,
+            sample_format: SampleFormat::Int,
+        }
+    }
+
+    /// A fresh scratch path per test, so tests running in parallel don't
+    /// clobber each other's WAV file.
+    fn scratch_path(name: &str) -> String {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir()
+            .join(format!("icfp-decode-test-{}-{}.wav", name, n))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn round_trip() {
+        let path = scratch_path("round_trip");
+        let symbol = Symbol::Pair(Symbol::Lit(Number::from(256)).into(), Symbol::Nil.into());
+
+        encode(&path, &symbol, wav_spec()).unwrap();
+        let decoded = decode(&path, None).unwrap();
+
+        assert_eq!(decoded, symbol);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn round_trip_with_trailing_zero_padding() {
+        // Simulate a recording that keeps running after the real
+        // transmission ends, padding the tail with silence.
+        let path = scratch_path("trailing_padding");
+        let symbol = Symbol::Lit(Number::from(1));
+        let spec = wav_spec();
+
+        let mut writer = WavWriter::create(&path, spec).unwrap();
+        for bit in modulate(&symbol) {
+            let amplitude = if bit { 1.0 } else { -1.0 };
+            for _ in 0..SAMPLES_PER_BIT {
+                write_sample(&mut writer, spec, amplitude).unwrap();
+            }
+        }
+        for _ in 0..(SAMPLES_PER_BIT * 3) {
+            write_sample(&mut writer, spec, 0.0).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let decoded = decode(&path, None).unwrap();
+
+        assert_eq!(decoded, symbol);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn round_trip_with_short_final_window() {
+        // Write each bit's window at a clock of 4 samples, except the last
+        // bit, which gets only 3: the recording's sample count isn't an
+        // exact multiple of `samples_per_bit`, so `chunks` yields a short
+        // final window that still has to majority-vote correctly.
+        let path = scratch_path("short_final_window");
+        let samples_per_bit = 4;
+        let symbol = Symbol::Lit(Number::from(0)); // modulates to "010"
+        let bits = modulate(&symbol);
+        let spec = wav_spec();
+
+        let mut writer = WavWriter::create(&path, spec).unwrap();
+        for (i, bit) in bits.iter().enumerate() {
+            let amplitude = if *bit { 1.0 } else { -1.0 };
+            let window = if i + 1 == bits.len() {
+                samples_per_bit - 1
+            } else {
+                samples_per_bit
+            };
+            for _ in 0..window {
+                write_sample(&mut writer, spec, amplitude).unwrap();
+            }
+        }
+        writer.finalize().unwrap();
+
+        let decoded = decode(&path, Some(samples_per_bit)).unwrap();
+
+        assert_eq!(decoded, symbol);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -3,6 +3,7 @@ use std::ops::Deref;
 
 pub mod ast;
 pub mod client;
+pub mod decode;
 pub mod parser;
 pub mod stack_interpreter;
 
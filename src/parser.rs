@@ -6,7 +6,7 @@ use pest::iterators::Pairs;
 use pest::Parser;
 use pest_derive::Parser;
 
-use crate::ast::{Identifier, Statement, Symbol};
+use crate::ast::{Identifier, Number, Statement, Symbol};
 
 #[derive(Parser)]
 #[grammar = "parser/grammar.pest"] // relative to src
@@ -26,7 +26,7 @@ fn parse_pair(pair: Pair<'_, Rule>) -> Symbol {
         Rule::cons => Symbol::Cons,
         Rule::car => Symbol::Car,
         Rule::cdr => Symbol::Cdr,
-        Rule::number => Symbol::Lit(i64::from_str(pair.as_str()).unwrap()),
+        Rule::number => Symbol::Lit(Number::from_str(pair.as_str()).unwrap()),
         Rule::nil => Symbol::Nil,
         Rule::eq => Symbol::Eq,
         Rule::lt => Symbol::Lt,
@@ -19,7 +19,7 @@ fn parse_assignment() {
     use crate::ast::Identifier;
     use crate::ast::Symbol::*;
     let expected = map!(
-        Identifier::id(1029) => vec![Ap, Ap, Cons, Lit(7), Ap, Ap, Cons, Lit(123229502148636), Nil]
+        Identifier::id(1029) => vec![Ap, Ap, Cons, Lit(7.into()), Ap, Ap, Cons, Lit(123229502148636i64.into()), Nil]
     );
 
     assert_eq!(expected, map);
@@ -35,7 +35,7 @@ fn parse_inc() {
     use crate::ast::Identifier;
     use crate::ast::Symbol::*;
     let expected = map!(
-        Identifier::id(1029) => vec![Ap, Inc, Lit(300), Nil]
+        Identifier::id(1029) => vec![Ap, Inc, Lit(300.into()), Nil]
     );
     assert_eq!(expected, map);
     println!("{:?}", map);
@@ -66,7 +66,7 @@ fn parse_mod() {
     use crate::ast::Identifier;
     use crate::ast::Symbol::*;
     let expected = map!(
-        Identifier::id(0) => vec![Ap, Mod, Lit(0)]
+        Identifier::id(0) => vec![Ap, Mod, Lit(0.into())]
     );
 
     assert_eq!(expected, map);
@@ -82,7 +82,7 @@ fn parse_mod_with_negative() {
     use crate::ast::Identifier;
     use crate::ast::Symbol::*;
     let expected = map!(
-        Identifier::id(0) => vec![Ap, Mod, Lit(-10)]
+        Identifier::id(0) => vec![Ap, Mod, Lit((-10).into())]
     );
 
     assert_eq!(expected, map);
@@ -96,7 +96,7 @@ fn parse_list() {
     use crate::ast::Identifier;
     use crate::ast::Symbol::*;
     let expected = map!(
-        Identifier::id(1029) => vec![List(vec![Lit(300)]), Nil]
+        Identifier::id(1029) => vec![List(vec![Lit(300.into())]), Nil]
     );
     assert_eq!(expected, map);
     println!("{:?}", map);
@@ -109,7 +109,7 @@ fn parse_list_many_items() {
     use crate::ast::Identifier;
     use crate::ast::Symbol::*;
     let expected = map!(
-        Identifier::id(1029) => vec![List(vec![Lit(300), Lit(200), Lit(100)]), Nil]
+        Identifier::id(1029) => vec![List(vec![Lit(300.into()), Lit(200.into()), Lit(100.into())]), Nil]
     );
     assert_eq!(expected, map);
     println!("{:?}", map);
@@ -141,7 +141,7 @@ fn parse_list_nested() {
     use crate::ast::Identifier;
     use crate::ast::Symbol::*;
     let expected = map!(
-        Identifier::id(1029) => vec![List(vec![Lit(300), List(vec![Lit(200), Lit(100)])]), Nil]
+        Identifier::id(1029) => vec![List(vec![Lit(300.into()), List(vec![Lit(200.into()), Lit(100.into())])]), Nil]
     );
     assert_eq!(map, expected);
     println!("{:?}", map);
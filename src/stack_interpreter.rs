@@ -4,18 +4,19 @@ use std::sync::Mutex;
 
 use crate::ast::lower_symbols;
 use crate::ast::modulations;
+use crate::client::{backoff_delay, SyncClient};
 
 use super::ast::{Identifier, Number, Statement, Symbol, SymbolCell};
-use image::{GrayImage, ImageFormat};
+use image::{GrayImage, Rgb, RgbImage};
+use num_traits::{Pow, ToPrimitive};
 use std::mem;
-use std::time::SystemTime;
 
 type StackEnvironment = HashMap<Identifier, SymbolCell>;
 type RuntimeStack = Vec<SymbolCell>;
 
-trait Effects {
+pub trait Effects {
     fn send(&self, content: String) -> String;
-    fn display(&self, image: &GrayImage);
+    fn display(&self, image: &RgbImage);
 }
 
 struct NullEffects();
@@ -25,15 +26,101 @@ impl Effects for NullEffects {
         content
     }
 
-    fn display(&self, _image: &GrayImage) {
+    fn display(&self, _image: &RgbImage) {
         // do nothing
     }
 }
 
+/// Error from [`HttpEffects`]'s send-and-confirm loop: either the transport
+/// itself failed, the proxy answered with a non-200 status, or every
+/// attempt was exhausted without either of those being the final word.
+#[derive(Debug)]
+pub enum SendError {
+    Http(reqwest::Error),
+    UnexpectedStatus(reqwest::StatusCode),
+    RetriesExhausted,
+}
+
+/// Drives `Symbol::Send` against the real alien proxy: modulates the
+/// operand, POSTs it to `server_url`, and demodulates the reply. Non-200
+/// responses and transport errors are retried a bounded number of times
+/// with exponential backoff before giving up, mirroring the send-and-confirm
+/// pattern a real game client needs against a flaky endpoint.
+pub struct HttpEffects {
+    server_url: String,
+    api_key: String,
+    max_attempts: u32,
+}
+
+impl HttpEffects {
+    pub fn new(server_url: &str, api_key: &str) -> HttpEffects {
+        HttpEffects {
+            server_url: server_url.trim_end_matches('/').to_string(),
+            api_key: api_key.to_string(),
+            max_attempts: 5,
+        }
+    }
+
+    fn send_and_confirm(&self, body: String) -> Result<String, SendError> {
+        let client = reqwest::blocking::Client::new();
+        let mut last_err = None;
+
+        for attempt in 0..self.max_attempts {
+            if attempt > 0 {
+                std::thread::sleep(backoff_delay(attempt));
+            }
+
+            let outcome = client
+                .post(&format!("{}/aliens/send", self.server_url))
+                .query(&[("apiKey", self.api_key.as_str())])
+                .body(body.clone())
+                .send();
+
+            match outcome {
+                Ok(response) if response.status().is_success() => {
+                    return response.text().map_err(SendError::Http);
+                }
+                Ok(response) => last_err = Some(SendError::UnexpectedStatus(response.status())),
+                Err(err) => last_err = Some(SendError::Http(err)),
+            }
+        }
+
+        Err(last_err.unwrap_or(SendError::RetriesExhausted))
+    }
+}
+
+impl SyncClient for HttpEffects {
+    type Error = SendError;
+
+    fn send(&self, content: String) -> Result<String, SendError> {
+        self.send_and_confirm(content)
+    }
+}
+
+impl Effects for HttpEffects {
+    fn send(&self, content: String) -> String {
+        SyncClient::send(self, content)
+            .unwrap_or_else(|err| panic!("alien proxy send failed after retries: {:?}", err))
+    }
+
+    fn display(&self, _image: &RgbImage) {
+        // HttpEffects only drives the wire protocol; rendering is left to
+        // whichever Effects impl the caller composes it with.
+    }
+}
+
 pub struct VM {
     heap: StackEnvironment,
     stack: RuntimeStack,
     effects: Box<dyn Effects>,
+    // Memoizes `resolve` by the forced cell's `Rc` identity, so a shared
+    // subterm (e.g. a combinator's captured argument referenced twice) is
+    // only ever reduced to WHNF once, however many places point to it. Since
+    // `SymbolCell`s are hash-consed (see `ast::intern`), structurally-equal
+    // subterms built independently share one identity too, so this cache is
+    // what makes graph reduction of recursive programs like `galaxy`
+    // tractable without an arbitrary step cap.
+    thunk_cache: HashMap<usize, SymbolCell>,
 }
 
 impl VM {
@@ -42,6 +129,16 @@ impl VM {
             heap: StackEnvironment::new(),
             stack: RuntimeStack::new(),
             effects: Box::from(NullEffects()),
+            thunk_cache: HashMap::new(),
+        })
+    }
+
+    pub fn new_effects(effects: Box<dyn Effects>) -> Mutex<Self> {
+        Mutex::new(VM {
+            heap: StackEnvironment::new(),
+            stack: RuntimeStack::new(),
+            effects,
+            thunk_cache: HashMap::new(),
         })
     }
 
@@ -66,11 +163,23 @@ pub trait Resolve {
     fn pop(&self) -> SymbolCell;
     fn push(&self, symbol: SymbolCell);
     fn var(&self, id: Identifier) -> SymbolCell;
+    /// Builds `symbols` into an expression the same way a statement's
+    /// right-hand side is lowered, and runs it to completion against this
+    /// VM's heap and effects.
+    fn run_symbols(&self, symbols: &[Symbol]) -> SymbolCell;
 }
 
 impl Resolve for Mutex<VM> {
     fn resolve(&self, symbol: &SymbolCell) -> SymbolCell {
-        dbg!(run_expression(symbol.clone(), self))
+        let key = symbol.thunk_key();
+
+        if let Some(forced) = self.lock().unwrap().thunk_cache.get(&key) {
+            return forced.clone();
+        }
+
+        let forced = run_expression(symbol.clone(), self);
+        self.lock().unwrap().thunk_cache.insert(key, forced.clone());
+        forced
     }
 
     fn pop(&self) -> SymbolCell {
@@ -84,12 +193,20 @@ impl Resolve for Mutex<VM> {
     fn var(&self, id: Identifier) -> SymbolCell {
         self.lock().unwrap().var(id)
     }
+
+    fn run_symbols(&self, symbols: &[Symbol]) -> SymbolCell {
+        run_expression(build_tree(symbols), self)
+    }
 }
 
 fn build_symbol_tree(statement: &Statement) -> SymbolCell {
+    build_tree(&statement.1)
+}
+
+fn build_tree<T: Into<SymbolCell> + Clone>(symbols: &[T]) -> SymbolCell {
     let mut stack = Vec::<SymbolCell>::new();
 
-    let lowered_symbols: Vec<SymbolCell> = lower_symbols(&statement.1);
+    let lowered_symbols: Vec<SymbolCell> = lower_symbols(symbols);
 
     for inst in lowered_symbols.iter().rev() {
         let val = lower_applies(inst, &mut stack);
@@ -103,13 +220,12 @@ fn build_symbol_tree(statement: &Statement) -> SymbolCell {
         stack
     );
 
-    dbg!(stack).pop().unwrap()
+    stack.pop().unwrap()
 }
 
 fn lower_applies(op: &SymbolCell, operands: &mut Vec<SymbolCell>) -> SymbolCell {
     match op.deref() {
         Symbol::Ap => {
-            dbg!(&operands);
             let fun = operands.pop().unwrap();
             let arg = operands.pop().unwrap();
             Symbol::Closure {
@@ -154,7 +270,7 @@ where
 
 fn stack_lit1<T: Into<SymbolCell>, F: FnOnce(Number) -> T>(vm: &Mutex<VM>, f: F) -> SymbolCell {
     op1(vm, |arg| match vm.resolve(&arg).deref() {
-        Symbol::Lit(x) => f(*x).into(),
+        Symbol::Lit(x) => f(x.clone()).into(),
         arg => unreachable!("Non-literal operand: {:?}", arg),
     })
 }
@@ -165,7 +281,7 @@ fn stack_lit2<T: Into<SymbolCell>, F: FnOnce(Number, Number) -> T>(
 ) -> SymbolCell {
     op2(vm, |first, second| {
         match (vm.resolve(&first).deref(), vm.resolve(&second).deref()) {
-            (Symbol::Lit(x), Symbol::Lit(y)) => f(*x, *y).into(),
+            (Symbol::Lit(x), Symbol::Lit(y)) => f(x.clone(), y.clone()).into(),
             args => unreachable!("Non-literal operands: {:?}", args),
         }
     })
@@ -202,9 +318,111 @@ fn iter_symbols(vm: &Mutex<VM>, symbol: SymbolCell) -> SymbolIter {
     SymbolIter { vm, symbol }
 }
 
+/// Forces `symbol` to weak-head-normal list form: `Resolve::resolve` only forces
+/// the head, so a saturated combinator application left thunked in a list's
+/// tail - e.g. a `Closure` capturing two args over `Cons` - stays stuck
+/// until something asks for it. This drives `resolve` at every level of a
+/// `Pair`/`Nil`/`Lit` spine so the whole structure is real data, which is
+/// what `ast::modulations::modulate` requires.
+pub fn normalize(vm: &Mutex<VM>, symbol: &SymbolCell) -> SymbolCell {
+    let forced = vm.resolve(symbol);
+
+    match forced.deref() {
+        Symbol::Pair(car, cdr) => Symbol::Pair(normalize(vm, car), normalize(vm, cdr)).into(),
+        _ => forced,
+    }
+}
+
+/// Resolves a points list (as produced by `Draw`/`MultipleDraw`'s operand)
+/// into raw `(x, y)` coordinates. Unlike pixel coordinates these may be
+/// negative, so callers need a bounding box before they can be plotted.
+fn resolve_points(vm: &Mutex<VM>, points: SymbolCell) -> Vec<(i64, i64)> {
+    iter_symbols(vm, points)
+        .map(|sym| match sym.deref() {
+            Symbol::Pair(x, y) => {
+                let x = vm.resolve(x);
+                let y = vm.resolve(y);
+                match (x.deref(), y.deref()) {
+                    (Symbol::Lit(x), Symbol::Lit(y)) => (x.to_i64().unwrap(), y.to_i64().unwrap()),
+                    _ => panic!(),
+                }
+            }
+            _ => panic!(),
+        })
+        .collect()
+}
+
+/// The union bounding box of every point across all layers, as `(origin,
+/// size)`, where `origin` is the `(min_x, min_y)` offset needed to translate
+/// points into non-negative canvas coordinates. `None` if every layer is
+/// empty.
+fn layer_bounds(layers: &[Vec<(i64, i64)>]) -> Option<((i64, i64), (u32, u32))> {
+    let mut points = layers.iter().flatten();
+    let &(mut min_x, mut min_y) = points.next()?;
+    let (mut max_x, mut max_y) = (min_x, min_y);
+
+    for &(x, y) in points {
+        min_x = min_x.min(x);
+        min_y = min_y.min(y);
+        max_x = max_x.max(x);
+        max_y = max_y.max(y);
+    }
+
+    let width = (max_x - min_x) as u32 + 1;
+    let height = (max_y - min_y) as u32 + 1;
+    Some(((min_x, min_y), (width, height)))
+}
+
+/// Renders a single points list onto a canvas sized to just fit it. Used to
+/// produce the `Symbol::Image` value `Draw`/`MultipleDraw` hand back to the
+/// galaxy program, as distinct from the RGB frame composited for display.
+fn image_from_points(points: &[(i64, i64)]) -> GrayImage {
+    let ((origin_x, origin_y), (width, height)) =
+        layer_bounds(&[points.to_vec()]).unwrap_or(((0, 0), (1, 1)));
+
+    let mut image = GrayImage::new(width, height);
+    for &(x, y) in points {
+        image.put_pixel((x - origin_x) as u32, (y - origin_y) as u32, [255u8].into());
+    }
+
+    image
+}
+
+/// A fixed cycle of high-contrast colors, one per layer, so overlapping
+/// point-list layers composited by [`composite_layers`] stay distinguishable.
+const PALETTE: [[u8; 3]; 6] = [
+    [255, 0, 0],
+    [0, 255, 0],
+    [0, 128, 255],
+    [255, 255, 0],
+    [255, 0, 255],
+    [0, 255, 255],
+];
+
+/// Composites several point-list layers (e.g. the sublists `MultipleDraw` or
+/// an `interact` step hands back) onto one `RgbImage`, assigning each layer a
+/// color from `PALETTE` cycling as needed. The canvas is auto-sized to the
+/// union bounding box of all layers (supporting negative coordinates via an
+/// origin offset) rather than a hardcoded 640x480. Where layers overlap, the
+/// later layer's color wins.
+fn composite_layers(layers: &[Vec<(i64, i64)>]) -> RgbImage {
+    let ((origin_x, origin_y), (width, height)) =
+        layer_bounds(layers).unwrap_or(((0, 0), (1, 1)));
+
+    let mut image = RgbImage::new(width, height);
+    for (layer, points) in layers.iter().enumerate() {
+        let color = Rgb(PALETTE[layer % PALETTE.len()]);
+        for &(x, y) in points {
+            image.put_pixel((x - origin_x) as u32, (y - origin_y) as u32, color);
+        }
+    }
+
+    image
+}
+
 pub fn run_function(function: SymbolCell, vm: &Mutex<VM>) {
     let result = match function.deref() {
-        Symbol::Var(id) => dbg!(vm.var(id.clone()).clone()),
+        Symbol::Var(id) => vm.var(id.clone()).clone(),
         Symbol::Lit(_) => function.clone(),
         Symbol::Pair(_, _) => function.clone(),
         Symbol::Modulated(_) => function.clone(),
@@ -215,7 +433,7 @@ pub fn run_function(function: SymbolCell, vm: &Mutex<VM>) {
         Symbol::Mul => stack_lit2(vm, |x, y| x * y),
         Symbol::Div => stack_lit2(vm, |x, y| x / y),
         Symbol::If0 => op3(vm, |test, first, second| {
-            if vm.resolve(&test).deref() == &Symbol::Lit(0) {
+            if vm.resolve(&test).deref() == &Symbol::Lit(0.into()) {
                 first
             } else {
                 second
@@ -239,7 +457,7 @@ pub fn run_function(function: SymbolCell, vm: &Mutex<VM>) {
         Symbol::F => op2(vm, |_, y| y),
 
         Symbol::Mod => op1(vm, |op| {
-            let vec = modulations::modulate(&op, vm);
+            let vec = modulations::modulate(normalize(vm, &op).deref());
             Symbol::Modulated(vec).into()
         }),
 
@@ -247,10 +465,20 @@ pub fn run_function(function: SymbolCell, vm: &Mutex<VM>) {
             Symbol::Modulated(val) => modulations::demodulate(val.clone()).into(),
             _ => unreachable!("Dem with invalid operands"),
         }),
-        // Symbol::Send => {},
-        Symbol::Neg => stack_lit1(vm, |x| Symbol::Lit(-x.clone())),
+        Symbol::Send => op1(vm, |op| {
+            let resolved = normalize(vm, &op);
+            let body = modulations::modulate_to_string(resolved.deref());
+            let reply = vm.lock().unwrap().effects.send(body);
+            modulations::demodulate_string(&reply)
+                .expect("malformed modulated reply from alien proxy")
+                .into()
+        }),
+        Symbol::Neg => stack_lit1(vm, |x| Symbol::Lit(-x)),
 
-        Symbol::Pwr2 => stack_lit1(vm, |x| i64::pow(2, x as u32)),
+        Symbol::Pwr2 => stack_lit1(vm, |x| {
+            let exponent = x.to_u32().expect("pwr2 exponent out of range");
+            Symbol::Lit(Number::from(2).pow(exponent))
+        }),
         Symbol::I => op1(vm, |op| op.clone()),
 
         Symbol::Cons => op2(vm, |op1, op2| Symbol::Pair(op1.clone(), op2.clone()).into()),
@@ -277,61 +505,52 @@ pub fn run_function(function: SymbolCell, vm: &Mutex<VM>) {
         }),
 
         Symbol::Draw => op1(vm, |x| {
-            let mut image = GrayImage::new(640, 480);
-            for sym in iter_symbols(vm, x) {
-                match sym.deref() {
-                    Symbol::Pair(x, y) => {
-                        let x = vm.resolve(x);
-                        let y = vm.resolve(y);
-                        match (x.deref(), y.deref()) {
-                            (&Symbol::Lit(x), &Symbol::Lit(y)) => {
-                                image.put_pixel(x as u32, y as u32, [255u8].into())
-                            }
-                            _ => panic!(),
-                        }
-                    }
-                    _ => panic!(),
-                }
-            }
+            let points = resolve_points(vm, x);
+
+            vm.lock()
+                .unwrap()
+                .effects
+                .deref()
+                .display(&composite_layers(&[points.clone()]));
+
+            Symbol::Image(image_from_points(&points)).into()
+        }),
 
-            let name = SystemTime::now()
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .unwrap();
+        Symbol::MultipleDraw => op1(vm, |x| {
+            let layers: Vec<Vec<(i64, i64)>> = iter_symbols(vm, x)
+                .map(|points| resolve_points(vm, points))
+                .collect();
 
-            image
-                .save_with_format(format!("/tmp/{}.png", name.as_secs()), ImageFormat::Png)
-                .unwrap();
+            vm.lock()
+                .unwrap()
+                .effects
+                .deref()
+                .display(&composite_layers(&layers));
 
-            Symbol::Image(image).into()
+            let images: Vec<Symbol> = layers
+                .iter()
+                .map(|points| Symbol::Image(image_from_points(points)))
+                .collect();
+
+            Symbol::List(images).into()
         }),
 
         Symbol::Checkerboard => stack_lit2(vm, |x, y| {
-            let mut image = GrayImage::new(x as u32, y as u32);
-            for x in 0..x as u32 {
-                for y in 0..y as u32 {
+            let (x, y) = (x.to_u32().unwrap(), y.to_u32().unwrap());
+            let mut image = GrayImage::new(x, y);
+            for x in 0..x {
+                for y in 0..y {
                     let color = ((x % 2) ^ (y % 2)) as u8;
                     image.put_pixel(x, y, [255u8 * color].into())
                 }
             }
 
-            vm.lock().unwrap().effects.deref().display(&image);
-
-            let name = SystemTime::now()
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .unwrap();
+            let rgb = RgbImage::from_fn(image.width(), image.height(), |x, y| {
+                let luma = image.get_pixel(x, y).0[0];
+                Rgb([luma, luma, luma])
+            });
+            vm.lock().unwrap().effects.deref().display(&rgb);
 
-            image
-                .save_with_format(format!("/tmp/{}.png", name.as_secs()), ImageFormat::Png)
-                .unwrap();
-
-            // let name = SystemTime::now()
-            //     .duration_since(SystemTime::UNIX_EPOCH)
-            //     .unwrap();
-            //
-            // image
-            //     .save_with_format(format!("/tmp/{}.png", name.as_secs()), ImageFormat::Png)
-            //     .unwrap();
-            //
             Symbol::Image(image)
         }),
         Symbol::S => {
@@ -404,21 +623,14 @@ pub fn run_function(function: SymbolCell, vm: &Mutex<VM>) {
         func => unimplemented!("Function not supported: {:?}", func),
     };
 
-    vm.push(dbg!(result));
+    vm.push(result);
 }
 
 pub fn run_expression(symbol: SymbolCell, vm: &Mutex<VM>) -> SymbolCell {
     let mut op: SymbolCell = symbol;
-    let mut count = 0;
     loop {
-        dbg!(&op);
-        // dbg!(&vm.stack);
-        if count > 1000 {
-            panic!();
-        }
-        count += 1;
         run_function(op.clone(), vm);
-        let sym: SymbolCell = dbg!(vm.pop());
+        let sym: SymbolCell = vm.pop();
         match sym.deref() {
             // :3 = cons
             Symbol::Closure { .. } => op = sym.clone(),
@@ -451,6 +663,7 @@ pub fn run(symbol: SymbolCell, environment: &StackEnvironment) -> SymbolCell {
         stack: RuntimeStack::new(),
         heap: environment.clone(),
         effects: Box::from(NullEffects()),
+        thunk_cache: HashMap::new(),
     };
     run_expression(symbol, &Mutex::new(vm))
 }
@@ -460,7 +673,7 @@ pub fn stack_interpret(statements: Vec<Statement>) -> Symbol {
     let last_statement_id = statements.last().unwrap().0.clone();
 
     for statement in statements.clone() {
-        let statements_rvalue = dbg!(build_symbol_tree(&statement));
+        let statements_rvalue = build_symbol_tree(&statement);
 
         env.insert(statement.0, statements_rvalue);
     }
@@ -479,13 +692,108 @@ pub fn eval_instructions<T: Into<Symbol> + Clone>(symbols: &[T]) -> Symbol {
     stack_interpret(vec![statement])
 }
 
+/// Why `interact` gave up: the protocol evaluated to something other than
+/// the `(flag, newState, data)` triple it's defined to return.
+#[derive(Debug)]
+pub struct MalformedTripleError {
+    pub symbol: String,
+}
+
+impl std::fmt::Display for MalformedTripleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "malformed interact result, expected (flag, state, data): {}",
+            self.symbol
+        )
+    }
+}
+
+impl std::error::Error for MalformedTripleError {}
+
+/// Drives the galaxy interaction protocol: repeatedly evaluates
+/// `ap ap ap protocol state vector` to WHNF and decodes the resulting
+/// `(flag, newState, data)` triple. When `flag` is `0` the protocol has come
+/// to rest: `data`'s layers of points are composited into one `RgbImage` and
+/// pushed through `Effects::display`, so an interactive front-end can render
+/// the frame, and are returned as one `GrayImage` per layer. Otherwise `data`
+/// is sent through the VM's `Send` effect, nothing is displayed, and the
+/// demodulated reply becomes the next `vector`.
+pub fn interact(
+    vm: &Mutex<VM>,
+    protocol: SymbolCell,
+    state: SymbolCell,
+    vector: (i64, i64),
+) -> Result<(SymbolCell, Vec<GrayImage>), MalformedTripleError> {
+    let (x, y) = vector;
+    let mut state = state;
+    let mut vector: SymbolCell = Symbol::Pair(Number::from(x).into(), Number::from(y).into()).into();
+
+    loop {
+        let result = vm.run_symbols(&[
+            Symbol::Ap,
+            Symbol::Ap,
+            Symbol::Ap,
+            protocol.deref().clone(),
+            state.deref().clone(),
+            vector.deref().clone(),
+        ]);
+
+        let (flag, new_state, data) = decode_triple(vm, &result)?;
+        let flag = vm.resolve(&flag);
+
+        if flag.deref() == &Symbol::Lit(Number::from(0)) {
+            return Ok((new_state, decode_images(vm, &data)));
+        }
+
+        vector = vm.run_symbols(&[Symbol::Ap, Symbol::Send, data.deref().clone()]);
+        state = new_state;
+    }
+}
+
+/// `interact` returns `(flag, newState, data)`, i.e. `Pair(flag, Pair(newState, Pair(data, Nil)))`.
+fn decode_triple(
+    vm: &Mutex<VM>,
+    result: &SymbolCell,
+) -> Result<(SymbolCell, SymbolCell, SymbolCell), MalformedTripleError> {
+    let malformed = |symbol: &Symbol| MalformedTripleError {
+        symbol: format!("{:?}", symbol),
+    };
+
+    match vm.resolve(result).deref() {
+        Symbol::Pair(flag, rest) => match vm.resolve(rest).deref() {
+            Symbol::Pair(new_state, rest) => match vm.resolve(rest).deref() {
+                Symbol::Pair(data, _nil) => Ok((flag.clone(), new_state.clone(), data.clone())),
+                other => Err(malformed(other)),
+            },
+            other => Err(malformed(other)),
+        },
+        other => Err(malformed(other)),
+    }
+}
+
+fn decode_images(vm: &Mutex<VM>, data: &SymbolCell) -> Vec<GrayImage> {
+    let layers: Vec<Vec<(i64, i64)>> = iter_symbols(vm, data.clone())
+        .map(|points| resolve_points(vm, points))
+        .collect();
+
+    vm.lock()
+        .unwrap()
+        .effects
+        .deref()
+        .display(&composite_layers(&layers));
+
+    layers.iter().map(|points| image_from_points(points)).collect()
+}
+
 #[cfg(test)]
 mod stack_tests {
     use crate::ast::{Statement, Symbol};
     use crate::parser::parse_as_lines;
     use crate::stack_interpreter::build_symbol_tree;
+    use std::ops::Deref;
 
-    use super::stack_interpret;
+    use super::{normalize, stack_interpret, VM};
     use super::Symbol::*;
 
     fn run_lines(lines: Vec<Statement>, expectation: Symbol) {
@@ -508,7 +816,7 @@ mod stack_tests {
     fn add() {
         let lines = parse_as_lines(":1 = ap ap add 2 1");
         let symbol = dbg!(stack_interpret(lines));
-        assert_eq!(symbol, Lit(3))
+        assert_eq!(symbol, Lit(3.into()))
     }
 
     #[test]
@@ -523,12 +831,12 @@ mod stack_tests {
         ap ap mul x0 0   =   0
         ap ap mul x0 1   =   x0
         */
-        run_test(":1 = ap ap mul 4 2", Lit(8));
-        run_test(":1 = ap ap mul 3 4", Lit(12));
-        run_test(":1 = ap ap mul 3 -2", Lit(-6));
-        run_test(":1 = ap ap mul -2 3", Lit(-6));
-        run_test(":1 = ap ap mul 4 0", Lit(0));
-        run_test(":1 = ap ap mul 4 1", Lit(4));
+        run_test(":1 = ap ap mul 4 2", Lit(8.into()));
+        run_test(":1 = ap ap mul 3 4", Lit(12.into()));
+        run_test(":1 = ap ap mul 3 -2", Lit((-6).into()));
+        run_test(":1 = ap ap mul -2 3", Lit((-6).into()));
+        run_test(":1 = ap ap mul 4 0", Lit(0.into()));
+        run_test(":1 = ap ap mul 4 1", Lit(4.into()));
     }
 
     #[test]
@@ -580,14 +888,14 @@ mod stack_tests {
         ap ap div x0 1   =   x0
         */
 
-        run_test(":1 = ap ap div 4 2", Lit(2));
-        run_test(":1 = ap ap div 4 3", Lit(1));
-        run_test(":1 = ap ap div 4 5", Lit(0));
-        run_test(":1 = ap ap div 5 2", Lit(2));
-        run_test(":1 = ap ap div 6 -2", Lit(-3));
-        run_test(":1 = ap ap div 5 -3", Lit(-1));
-        run_test(":1 = ap ap div -5 3", Lit(-1));
-        run_test(":1 = ap ap div -5 -3", Lit(1));
+        run_test(":1 = ap ap div 4 2", Lit(2.into()));
+        run_test(":1 = ap ap div 4 3", Lit(1.into()));
+        run_test(":1 = ap ap div 4 5", Lit(0.into()));
+        run_test(":1 = ap ap div 5 2", Lit(2.into()));
+        run_test(":1 = ap ap div 6 -2", Lit((-3).into()));
+        run_test(":1 = ap ap div 5 -3", Lit((-1).into()));
+        run_test(":1 = ap ap div -5 3", Lit((-1).into()));
+        run_test(":1 = ap ap div -5 -3", Lit(1.into()));
     }
 
     #[test]
@@ -602,17 +910,17 @@ mod stack_tests {
 
     #[test]
     fn cons() {
-        run_test(":1 = ap ap cons 1 2", Pair(Lit(1).into(), Lit(2).into()));
+        run_test(":1 = ap ap cons 1 2", Pair(Lit(1.into()).into(), Lit(2.into()).into()));
     }
 
     #[test]
     fn car() {
-        run_test(":1 = ap car ap ap cons 1 2", Lit(1));
+        run_test(":1 = ap car ap ap cons 1 2", Lit(1.into()));
     }
 
     #[test]
     fn cdr() {
-        run_test(":1 = ap cdr ap ap cons 1 2", Lit(2));
+        run_test(":1 = ap cdr ap ap cons 1 2", Lit(2.into()));
     }
 
     #[test]
@@ -627,10 +935,10 @@ mod stack_tests {
         ap ap t ap inc 5 t   =   6
         */
 
-        run_test(":1 = ap ap t 1 2", Lit(1));
+        run_test(":1 = ap ap t 1 2", Lit(1.into()));
         run_test(":1 = ap ap t t i", T);
         run_test(":1 = ap ap t t ap inc 5", T);
-        run_test(":1 = ap ap t ap inc 5 t", Lit(6));
+        run_test(":1 = ap ap t ap inc 5 t", Lit(6.into()));
     }
 
     #[test]
@@ -666,18 +974,52 @@ mod stack_tests {
     fn s_combinator() {
         let lines = parse_as_lines(":1 = ap ap ap s add inc 1");
         let symbol = dbg!(stack_interpret(lines));
-        assert_eq!(symbol, Lit(3));
+        assert_eq!(symbol, Lit(3.into()));
 
         let lines = parse_as_lines(":2 = ap ap ap s mul ap add 1 6");
         let symbol = dbg!(stack_interpret(lines));
-        assert_eq!(symbol, Lit(42));
+        assert_eq!(symbol, Lit(42.into()));
+    }
+
+    #[test]
+    fn shared_argument_is_forced_once() {
+        // `s add inc` duplicates its third argument into both `x z` and `y z`
+        // (see the `Symbol::S` case in `run_function`), so each level below
+        // reduces the previous level's value twice. Chained 19 levels deep,
+        // naive re-reduction would force :1 roughly 2^19 times; memoizing
+        // `resolve` by the shared cell's identity keeps the real cost
+        // proportional to the number of `s` applications instead.
+        let mut program = String::from(":1 = ap ap add 1 1");
+        for n in 2..=20 {
+            program.push_str(&format!("\n:{} = ap ap ap s add inc :{}", n, n - 1));
+        }
+
+        let lines = parse_as_lines(&program);
+        let symbol = dbg!(stack_interpret(lines));
+        assert_eq!(symbol, Lit(1572863.into()));
+    }
+
+    #[test]
+    fn deeply_nested_reduction_is_not_step_capped() {
+        // `run_expression` used to panic past 1000 reduction steps; a chain
+        // with no sharing to memoize still needs one step per `inc`, so this
+        // only passes now that the cap is gone.
+        let mut symbols = Vec::new();
+        for _ in 0..1500 {
+            symbols.push(Ap);
+            symbols.push(Inc);
+        }
+        symbols.push(Lit(0.into()));
+
+        let symbol = super::eval_instructions(&symbols);
+        assert_eq!(symbol, Lit(1500.into()));
     }
 
     #[test]
     fn c_combinator() {
         let lines = parse_as_lines(":1 = ap ap ap c add 1 2");
         let symbol = dbg!(stack_interpret(lines));
-        assert_eq!(symbol, Lit(3));
+        assert_eq!(symbol, Lit(3.into()));
     }
 
     #[test]
@@ -687,7 +1029,7 @@ mod stack_tests {
         :1 = ap ap ap b inc dec :0",
         );
         let symbol = dbg!(stack_interpret(lines));
-        assert_eq!(symbol, Lit(42));
+        assert_eq!(symbol, Lit(42.into()));
     }
 
     #[test]
@@ -698,7 +1040,7 @@ mod stack_tests {
         );
 
         let symbol = dbg!(stack_interpret(lines));
-        assert_eq!(symbol, Lit(3));
+        assert_eq!(symbol, Lit(3.into()));
     }
 
     #[test]
@@ -710,7 +1052,7 @@ mod stack_tests {
         );
 
         let symbol = dbg!(stack_interpret(lines));
-        assert_eq!(symbol, Lit(3));
+        assert_eq!(symbol, Lit(3.into()));
     }
 
     #[test]
@@ -734,7 +1076,7 @@ mod stack_tests {
         run_test(
             "modem = ap dem mod
                :1 = ap modem 1",
-            Lit(1),
+            Lit(1.into()),
         );
     }
 
@@ -751,7 +1093,7 @@ mod stack_tests {
         // ap ap ap interact x0 x18 ap ap vec x5 x6 = ( x19 , ap multipledraw x67 )
         //lines.extend_from_slice(&parse_as_lines("run = ap ap interact galaxy nil ( 0, 0 )"));
         lines.extend_from_slice(&parse_as_lines(":1 = ap modem 1"));
-        run_lines(lines, Lit(1));
+        run_lines(lines, Lit(1.into()));
     }
 
     #[test]
@@ -767,7 +1109,7 @@ mod stack_tests {
         // ap ap ap interact x0 x18 ap ap vec x5 x6 = ( x19 , ap multipledraw x67 )
         //lines.extend_from_slice(&parse_as_lines("run = ap ap interact galaxy nil ( 0, 0 )"));
         lines.extend_from_slice(&parse_as_lines(":1 = ap ap customdiv 4 2"));
-        run_lines(lines, Lit(2));
+        run_lines(lines, Lit(2.into()));
     }
 
     #[test]
@@ -776,4 +1118,60 @@ mod stack_tests {
         let lines = parse_as_lines(value);
         dbg!(build_symbol_tree(lines.first().unwrap()));
     }
+
+    #[test]
+    fn normalize_forces_stuck_cons_tail() {
+        // The same shape `run_start` leaves thunked: a saturated `cons`
+        // application that was never forced past its head.
+        let stuck: Symbol = Closure {
+            captured_arg: Nil.into(),
+            body: Closure {
+                captured_arg: Lit(63935.into()).into(),
+                body: Cons.into(),
+            }
+            .into(),
+        };
+
+        let vm = VM::new();
+        let normalized = normalize(&vm, &stuck.into());
+
+        assert_eq!(
+            normalized.deref().clone(),
+            Pair(Lit(63935.into()).into(), Nil.into())
+        );
+    }
+
+    #[test]
+    fn normalize_then_modulate_run_start() {
+        let symbol = stack_interpret(parse_as_lines(
+            ":1029 = ap ap cons 7 ap ap cons 123229502148636 nil
+:1030 = ap ap cons 2 ap ap cons 7 nil
+:1031 = ap ap cons 4 ap ap cons 21855 nil
+:1032 = ap ap cons 7 ap ap cons 560803991675135 nil
+:1034 = ap ap cons 5 ap ap cons 33554431 nil
+:1035 = ap ap cons 5 ap ap cons 30309607 nil
+:1036 = ap ap cons 3 ap ap cons 463 nil
+:1037 = ap ap cons 4 ap ap cons 48063 nil
+:1038 = ap ap cons 7 ap ap cons 10880 nil
+:1039 = ap ap cons 5 ap ap cons 15265326 nil
+:1040 = ap ap cons 5 ap ap cons 18472561 nil
+:1041 = ap ap cons 4 ap ap cons 64959 nil
+:1042 = ap ap cons 4 ap ap cons 63935 nil",
+        ));
+
+        let vm = VM::new();
+        let normalized = normalize(&vm, &symbol.into());
+
+        assert_eq!(
+            normalized.deref().clone(),
+            Pair(
+                Lit(4.into()).into(),
+                Pair(Lit(63935.into()).into(), Nil.into()).into()
+            )
+        );
+
+        // `modulate` no longer has to unimplemented!() on the stuck Closure
+        // tail once it's been normalized.
+        crate::ast::modulations::modulate(normalized.deref());
+    }
 }
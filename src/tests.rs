@@ -6,7 +6,19 @@ fn run_inc_1() {
     let symbol = super::run(":1096 = ap inc 1");
     dbg!(&symbol);
 
-    assert_eq!(symbol, Lit(2))
+    assert_eq!(symbol, Lit(2.into()))
+}
+
+#[test]
+fn run_inc_big_number() {
+    // exceeds i64::MAX, exercising the arbitrary-precision `Lit`.
+    let symbol = super::run(":1 = ap inc 99999999999999999999999999999999");
+    dbg!(&symbol);
+
+    assert_eq!(
+        symbol,
+        Lit("100000000000000000000000000000000".parse().unwrap())
+    )
 }
 
 #[test]
@@ -17,7 +29,7 @@ fn run_inc_var() {
     );
     dbg!(&symbol);
 
-    assert_eq!(symbol, Lit(3))
+    assert_eq!(symbol, Lit(3.into()))
 }
 
 #[test]
@@ -29,7 +41,7 @@ fn test_lookahead() {
     );
     dbg!(&symbol);
 
-    assert_eq!(symbol, Lit(3))
+    assert_eq!(symbol, Lit(3.into()))
 }
 
 #[test]
@@ -37,7 +49,7 @@ fn test_laziness() {
     let symbol = super::run(":1 = ap ap ap if0 1 :1 3");
     dbg!(&symbol);
 
-    assert_eq!(symbol, Lit(3))
+    assert_eq!(symbol, Lit(3.into()))
 }
 
 #[test]
@@ -45,7 +57,7 @@ fn run_simple_add() {
     let symbol = super::run(":1 = ap ap add 1 2");
     dbg!(&symbol);
 
-    assert_eq!(symbol, Lit(3))
+    assert_eq!(symbol, Lit(3.into()))
 }
 
 #[test]
@@ -57,7 +69,7 @@ fn run_simple() {
     );
     dbg!(&symbol);
 
-    assert_eq!(symbol, Lit(3))
+    assert_eq!(symbol, Lit(3.into()))
 }
 
 #[test]
@@ -82,11 +94,11 @@ fn run_start() {
     assert_eq!(
         symbol,
         Pair(
-            Lit(4).into(),
+            Lit(4.into()).into(),
             Closure {
                 captured_arg: Nil.into(),
                 body: Closure {
-                    captured_arg: Lit(63935).into(),
+                    captured_arg: Lit(63935.into()).into(),
                     body: Cons.into()
                 }
                 .into()